@@ -0,0 +1,205 @@
+use aoc_config::Config;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Scans the commands directory named in `aoc.toml` and generates the module declarations plus the
+// solution registry, so dropping a new `dayNN<part>.rs` into a year's `y<year>` directory is enough
+// to get a subcommand. Files with a suffix (`day8b_smartbf.rs`) become alternative variants of the
+// same part. It also turns every recorded answer into a regression test for each variant.
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let config = Config::discover(&manifest_dir).unwrap_or_else(|e| panic!("{e}"));
+    println!("cargo:rerun-if-changed={}", config.root.join(aoc_config::FILE_NAME).display());
+    println!("cargo:rerun-if-changed={}", config.commands.display());
+
+    // templates such as day0.rs sit next to the year directories and are compiled, not registered
+    let mut generated = String::new();
+    let templates = scan(&config.commands, None);
+    for module in &templates {
+        writeln!(generated, "#[path = {:?}]", module.path.display().to_string()).unwrap();
+        writeln!(generated, "pub mod {};", module.name).unwrap();
+    }
+
+    let mut modules = Vec::new();
+    for year in config.years() {
+        let year_dir = config.commands_dir(year);
+        println!("cargo:rerun-if-changed={}", year_dir.display());
+        println!("cargo:rerun-if-changed={}", config.answers_path(year).display());
+        // the year module sees everything in `commands`, so solutions keep using `super::`
+        writeln!(generated, "\npub mod y{year} {{\n    use super::*;").unwrap();
+        for module in scan(&year_dir, Some(year)) {
+            writeln!(generated, "    #[path = {:?}]", module.path.display().to_string()).unwrap();
+            writeln!(generated, "    pub mod {};", module.name).unwrap();
+            modules.push(module);
+        }
+        writeln!(generated, "}}").unwrap();
+    }
+
+    writeln!(generated, "\npub static SOLUTIONS: &[Solution] = &[").unwrap();
+    for module in &modules {
+        // helpers without a part, such as a shared day10.rs, are compiled but not registered
+        let (Some(year), Some(day), Some(part), Some(command)) =
+            (module.year, module.day, module.part, &module.command)
+        else {
+            continue;
+        };
+        // the command's own `CommandImpl::VARIANT` takes precedence, see `Solution::new`
+        writeln!(
+            generated,
+            "    Solution::new::<y{year}::{}::{command}>({year}, \"day{day}{part}\", {:?}, {}, {day}, Part::{}),",
+            module.name,
+            module.file_variant(),
+            module.suffix.is_empty(),
+            part.to_ascii_uppercase()
+        )
+        .unwrap();
+    }
+    writeln!(generated, "];").unwrap();
+
+    // the regression tests are ignored based on what `Module::slow` read from the source, so
+    // the build fails if that ever disagrees with the compiled `CommandImpl::SLOW`
+    writeln!(generated, "\n#[allow(clippy::assertions_on_constants)]\nconst _: () = {{").unwrap();
+    for module in &modules {
+        if let (Some(year), Some(_), Some(command)) = (module.year, module.part, &module.command) {
+            writeln!(
+                generated,
+                "    assert!({}<y{year}::{}::{command} as CommandImpl>::SLOW, {:?});",
+                if module.slow { "" } else { "!" },
+                module.name,
+                format!("build.rs misread SLOW in {}", module.path.display())
+            )
+            .unwrap();
+        }
+    }
+    writeln!(generated, "}};").unwrap();
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("commands.rs"), generated).expect("Could not write generated commands");
+    fs::write(out_dir.join("regression.rs"), regression_tests(&config, &modules))
+        .expect("Could not write generated tests");
+
+    // the list rustfmt follows in commands/mod.rs is kept by `xtask new-day`, only point out gaps
+    let mod_rs = fs::read_to_string(config.commands.join("mod.rs")).expect("Could not read mod.rs");
+    for module in templates.iter().chain(&modules) {
+        let path = module.path.strip_prefix(&config.commands).unwrap_or(&module.path);
+        if !mod_rs.contains(&format!("#[path = {:?}]", path.display().to_string())) {
+            println!(
+                "cargo:warning={} is missing from the rustfmt list in commands/mod.rs",
+                path.display()
+            );
+        }
+    }
+}
+
+// every `.rs` file in `dir` except mod.rs, in day order
+fn scan(dir: &Path, year: Option<u32>) -> Vec<Module> {
+    let mut modules: Vec<Module> = fs::read_dir(dir)
+        .unwrap_or_else(|e| panic!("Could not read {}: {e}", dir.display()))
+        .map(|entry| entry.expect("Could not read directory entry").path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "rs"))
+        .filter(|path| path.file_stem().map_or(false, |stem| stem != "mod"))
+        .map(|path| Module::from_path(path, year))
+        .collect();
+    modules.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+    modules
+}
+
+// one `#[test]` per variant and `[day_N.part]` entry, e.g. `y2023_day8b_test2` for the default and
+// `y2023_day8b_smartbf_test2`. Slow variants are ignored on anything but the examples.
+fn regression_tests(config: &Config, modules: &[Module]) -> String {
+    let mut generated = String::new();
+    for year in config.years() {
+        let answers_path = config.answers_path(year);
+        // a year without recorded answers yet simply gets no tests
+        let Ok(content) = fs::read_to_string(&answers_path) else {
+            continue;
+        };
+        let answers: toml::Table = content
+            .parse()
+            .unwrap_or_else(|e| panic!("{} is not valid TOML: {e}", answers_path.display()));
+
+        for module in modules.iter().filter(|module| module.year == Some(year)) {
+            let (Some(day), Some(part), Some(command)) = (module.day, module.part, &module.command)
+            else {
+                continue;
+            };
+            let recorded = answers
+                .get(&format!("day_{day}"))
+                .and_then(|parts| parts.get(part.to_string()))
+                .and_then(|inputs| inputs.as_table());
+            for (input, answer) in recorded.into_iter().flatten() {
+                let answer = match answer {
+                    toml::Value::String(answer) => answer.clone(),
+                    other => other.to_string(),
+                };
+                let test_name: String = format!("y{year}_{}_{input}", module.name)
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                    .collect();
+                writeln!(generated, "#[test]").unwrap();
+                if module.slow && !input.starts_with("test") {
+                    writeln!(generated, "#[ignore = \"slow\"]").unwrap();
+                }
+                writeln!(
+                    generated,
+                    "fn {test_name}() {{\n    let variant = <crate::commands::y{year}::{}::{command} as CommandImpl>::VARIANT;\n    \
+                     check({year}, \"day{day}{part}\", variant.unwrap_or({:?}), {input:?}, {answer:?});\n}}",
+                    module.name,
+                    module.file_variant(),
+                )
+                .unwrap();
+            }
+        }
+    }
+    generated
+}
+
+struct Module {
+    name: String,
+    // None for templates outside the year directories
+    year: Option<u32>,
+    path: PathBuf,
+    // None for helper modules, which aren't named after a day
+    day: Option<u32>,
+    part: Option<char>,
+    // whatever follows the part in the file name, e.g. `_smartbf`
+    suffix: String,
+    command: Option<String>,
+    // whether the command sets `const SLOW: bool = true`
+    slow: bool,
+}
+
+impl Module {
+    // file names follow `day<N><part>[suffix].rs`, e.g. day8b_smartbf.rs is day 8 part b. Any other
+    // name is a helper module without a day.
+    fn from_path(path: PathBuf, year: Option<u32>) -> Self {
+        let name = path.file_stem().unwrap().to_str().unwrap().to_owned();
+        let rest = name.strip_prefix("day").unwrap_or_default();
+        let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+        let day = digits.parse().ok();
+        let part = day.and(rest[digits.len()..].chars().next()).filter(|c| matches!(c, 'a' | 'b'));
+        let suffix = match part {
+            Some(part) => rest[digits.len() + part.len_utf8()..].to_owned(),
+            None => String::new(),
+        };
+
+        let source = fs::read_to_string(&path).expect("Could not read command source");
+        let command = source.split("impl CommandImpl for ").nth(1).map(|tail| {
+            tail.chars().take_while(|c| c.is_alphanumeric() || *c == '_').collect::<String>()
+        });
+
+        let slow = source.contains("const SLOW: bool = true;");
+
+        Module { name, year, path, day, part, suffix, command, slow }
+    }
+
+    // `smartbf` for day8b_smartbf.rs, `default` without a suffix
+    fn file_variant(&self) -> &str {
+        match self.suffix.trim_start_matches('_') {
+            "" => "default",
+            suffix => suffix,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use num::BigInt;
+use std::fmt;
+
+/// The result of a solution, formatted and labelled by the runner
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    BigInt(BigInt),
+    Str(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::BigInt(value) => write!(f, "{value}"),
+            Answer::Str(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Int(value.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Answer::Int(value),
+            Err(_) => Answer::BigInt(value.into()),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Answer::Int(value),
+            Err(_) => Answer::BigInt(value.into()),
+        }
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Answer::BigInt(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Str(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Str(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_unsigned() {
+        assert_eq!(Answer::from(42_usize), Answer::Int(42));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(BigInt::from(u64::MAX)));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-3).to_string(), "-3");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
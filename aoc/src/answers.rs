@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::{DynError, Part};
+use crate::runner::config;
+
+/// Answers known to be correct, read from `answers.toml`
+#[derive(Debug, Default)]
+pub struct Answers {
+    recorded: Vec<Recorded>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recorded {
+    pub day: u32,
+    pub part: Part,
+    /// Name of the input file without its `.txt` extension, e.g. `challenge1`
+    pub input: String,
+    pub answer: String,
+}
+
+type AnswerTable = BTreeMap<String, BTreeMap<String, BTreeMap<String, toml::Value>>>;
+
+pub fn answers_path(year: u32) -> PathBuf {
+    config().answers_path(year)
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, DynError> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read answers from {}: {e}", path.display()))?;
+        Self::parse(&content)
+    }
+
+    // expects `[day_N.part]` tables mapping input names to answers
+    pub fn parse(content: &str) -> Result<Self, DynError> {
+        let table: AnswerTable = toml::from_str(content)?;
+        let mut recorded = Vec::new();
+        for (day_key, parts) in table {
+            let day = day_key
+                .strip_prefix("day_")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("Expected a table named day_N, found {day_key}"))?;
+            for (part_key, inputs) in parts {
+                let part: Part = part_key.parse()?;
+                for (input, answer) in inputs {
+                    let answer = match answer {
+                        toml::Value::String(answer) => answer,
+                        toml::Value::Integer(answer) => answer.to_string(),
+                        other => {
+                            return Err(format!("Unsupported answer {other} for {day_key}").into())
+                        }
+                    };
+                    recorded.push(Recorded { day, part, input, answer });
+                }
+            }
+        }
+        recorded.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+        Ok(Answers { recorded })
+    }
+
+    pub fn for_part(&self, day: u32, part: Part) -> impl Iterator<Item = &Recorded> {
+        self.recorded.iter().filter(move |recorded| recorded.day == day && recorded.part == part)
+    }
+
+    pub fn get(&self, day: u32, part: Part, input: &str) -> Option<&str> {
+        self.for_part(day, part)
+            .find(|recorded| recorded.input == input)
+            .map(|recorded| recorded.answer.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let content = "
+        [day_8.b]
+        challenge1 = \"8906539031197\"
+        test2 = 6
+        ";
+        let answers = Answers::parse(content).unwrap();
+        assert_eq!(answers.get(8, Part::B, "challenge1"), Some("8906539031197"));
+        assert_eq!(answers.get(8, Part::B, "test2"), Some("6"));
+        assert_eq!(answers.get(8, Part::A, "test2"), None);
+        assert_eq!(answers.for_part(8, Part::B).count(), 2);
+    }
+
+    #[test]
+    fn test_parse_invalid_day() {
+        assert!(Answers::parse("[eight.a]\ntest1 = \"1\"").is_err());
+        assert!(Answers::parse("[day_8.c]\ntest1 = \"1\"").is_err());
+    }
+
+    #[test]
+    fn test_checked_in_answers() {
+        let answers = Answers::load(&answers_path(2023)).unwrap();
+        assert_eq!(answers.get(8, Part::B, "challenge1"), Some("8906539031197"));
+    }
+}
+
+// generated by build.rs, one test per recorded answer
+#[cfg(test)]
+mod regression {
+    use crate::commands::{CommandImpl, SOLUTIONS};
+    use crate::runner::inputs_dir;
+
+    fn check(year: u32, name: &str, variant: &str, input: &str, expected: &str) {
+        let solution = SOLUTIONS
+            .iter()
+            .find(|solution| {
+                (solution.year, solution.name, solution.variant) == (year, name, variant)
+            })
+            .expect("Tests are only generated for existing solutions");
+        let path = inputs_dir(year, solution.day).join(format!("{input}.txt"));
+        let answer = solution
+            .run_with_input(&path)
+            .unwrap_or_else(|e| panic!("{name} --variant {variant}: {e}"));
+        assert_eq!(answer.to_string(), expected);
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/regression.rs"));
+}
@@ -0,0 +1,305 @@
+use std::cell::Cell;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use clap::ArgMatches;
+use serde_json::json;
+
+use crate::commands::{Answer, DynError, Solution};
+use crate::runner::Input;
+
+thread_local! {
+    static PARSED: Cell<Option<Instant>> = Cell::new(None);
+}
+
+/// Mark the end of parsing in a solution, splitting its run time into parse and solve time.
+/// Solutions that never call this are timed as a whole, as solve time.
+pub fn mark_parsed() {
+    PARSED.with(|parsed| parsed.set(Some(Instant::now())));
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Sample {
+    parse: Option<Duration>,
+    solve: Duration,
+}
+
+impl Sample {
+    fn total(&self) -> Duration {
+        self.parse.unwrap_or_default() + self.solve
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Stats {
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+}
+
+impl Stats {
+    fn new(mut durations: Vec<Duration>) -> Option<Self> {
+        if durations.is_empty() {
+            return None;
+        }
+        durations.sort();
+        let n = durations.len();
+        let median = if n % 2 == 0 {
+            (durations[n / 2 - 1] + durations[n / 2]) / 2
+        } else {
+            durations[n / 2]
+        };
+        let mean = durations.iter().sum::<Duration>() / n as u32;
+        Some(Stats { min: durations[0], median, mean })
+    }
+}
+
+/// Timings of repeated runs of a solution on the same input
+pub struct Report {
+    answer: Answer,
+    iterations: usize,
+    // parse stats are only known if the solution marks where parsing ends
+    parse: Option<Stats>,
+    solve: Stats,
+    total: Stats,
+}
+
+/// Run a solution `iterations` times on the same input, timing parse and solve separately
+pub fn bench(
+    solution: &Solution,
+    matches: &ArgMatches,
+    input: &Input,
+    iterations: usize,
+) -> Result<Report, DynError> {
+    if iterations == 0 {
+        return Err("--bench needs at least one iteration".into());
+    }
+    let mut answer = None;
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        PARSED.with(|parsed| parsed.set(None));
+        let start = Instant::now();
+        let result = solution.solve(matches, input)?;
+        let end = Instant::now();
+        let sample = match PARSED.with(Cell::get) {
+            Some(parsed) => Sample { parse: Some(parsed - start), solve: end - parsed },
+            None => Sample { parse: None, solve: end - start },
+        };
+        samples.push(sample);
+        match &answer {
+            Some(previous) if *previous != result => {
+                return Err(format!("Answer changed between runs: {previous} vs {result}").into())
+            }
+            Some(_) => {}
+            None => answer = Some(result),
+        }
+    }
+
+    let parse = if samples.iter().all(|sample| sample.parse.is_some()) {
+        Stats::new(samples.iter().filter_map(|sample| sample.parse).collect())
+    } else {
+        None
+    };
+    Ok(Report {
+        answer: answer.expect("At least one iteration ran"),
+        iterations,
+        parse,
+        solve: Stats::new(samples.iter().map(|sample| sample.solve).collect()).unwrap(),
+        total: Stats::new(samples.iter().map(Sample::total).collect()).unwrap(),
+    })
+}
+
+impl Report {
+    pub fn print(&self, solution: &Solution) {
+        println!("{}: {}", solution.label(), self.answer);
+        println!("{} [{}] ({} iterations)", solution.name, solution.variant, self.iterations);
+        println!("{:<6}  {:>12}  {:>12}  {:>12}", "", "min", "median", "mean");
+        match &self.parse {
+            Some(parse) => print_stats("parse", parse),
+            None => println!("{:<6}  {:>12}  {:>12}  {:>12}", "parse", "-", "-", "-"),
+        }
+        print_stats("solve", &self.solve);
+        print_stats("total", &self.total);
+        if let Some(peak) = peak_rss_kb() {
+            println!("peak memory: {:.1} MiB", peak as f64 / 1024.0);
+        }
+    }
+
+    pub fn print_json(&self, solution: &Solution, input: &str) {
+        let stats = |stats: Option<&Stats>| {
+            stats.map(|stats| {
+                json!({
+                    "min_ns": stats.min.as_nanos(),
+                    "median_ns": stats.median.as_nanos(),
+                    "mean_ns": stats.mean.as_nanos(),
+                })
+            })
+        };
+        let report = json!({
+            "year": solution.year,
+            "day": solution.day,
+            "part": solution.part.to_string(),
+            "solution": solution.name,
+            "variant": solution.variant,
+            "input": input,
+            "answer": self.answer.to_string(),
+            "iterations": self.iterations,
+            "parse": stats(self.parse.as_ref()),
+            "solve": stats(Some(&self.solve)),
+            "total": stats(Some(&self.total)),
+            "peak_rss_kb": peak_rss_kb(),
+        });
+        println!("{report}");
+    }
+
+    /// Append the report as a line to a CSV file, writing the header if the file is new. A file
+    /// with different columns, e.g. from before a column was added, is an error.
+    pub fn append_csv(
+        &self,
+        path: &Path,
+        solution: &Solution,
+        input: &str,
+    ) -> Result<(), DynError> {
+        let header = match File::open(path) {
+            Ok(file) => BufReader::new(file).lines().next().transpose()?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => return Err(format!("Could not open {}: {e}", path.display()).into()),
+        };
+        if let Some(header) = header.as_deref().filter(|&header| header != CSV_HEADER) {
+            return Err(format!(
+                "{} has the columns {header}, expected {CSV_HEADER}",
+                path.display()
+            )
+            .into());
+        }
+        let new_file = header.is_none();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("Could not open {}: {e}", path.display()))?;
+        if new_file {
+            writeln!(file, "{CSV_HEADER}")?;
+        }
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        writeln!(file, "{timestamp},{}", self.csv_fields(solution, input))?;
+        Ok(())
+    }
+
+    fn csv_fields(&self, solution: &Solution, input: &str) -> String {
+        let stats = |stats: Option<&Stats>| match stats {
+            Some(stats) => format!(
+                "{},{},{}",
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos()
+            ),
+            None => ",,".to_string(),
+        };
+        // the input is a file name or path, the only field that may contain a comma or quote
+        let input = format!("\"{}\"", input.replace('"', "\"\""));
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            solution.year,
+            solution.name,
+            solution.variant,
+            solution.day,
+            solution.part,
+            input,
+            self.iterations,
+            stats(self.parse.as_ref()),
+            stats(Some(&self.solve)),
+            stats(Some(&self.total)),
+        )
+    }
+}
+
+/// Peak resident memory of this process so far, only known on Linux
+pub fn peak_rss_kb() -> Option<u64> {
+    let status = fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    line.trim_start_matches("VmHWM:").trim().trim_end_matches("kB").trim().parse().ok()
+}
+
+const CSV_HEADER: &str = "timestamp,year,solution,variant,day,part,input,iterations,\
+    parse_min_ns,parse_median_ns,parse_mean_ns,\
+    solve_min_ns,solve_median_ns,solve_mean_ns,\
+    total_min_ns,total_median_ns,total_mean_ns";
+
+fn print_stats(name: &str, stats: &Stats) {
+    println!(
+        "{:<6}  {:>12}  {:>12}  {:>12}",
+        name,
+        format!("{:.3?}", stats.min),
+        format!("{:.3?}", stats.median),
+        format!("{:.3?}", stats.mean)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new(vec![ms(4), ms(1), ms(3), ms(8)]).unwrap();
+        assert_eq!(stats, Stats { min: ms(1), median: ms(3) + ms(1) / 2, mean: ms(4) });
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!(stats.median, ms(3));
+        assert!(Stats::new(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn test_csv_fields() {
+        let solution = crate::commands::find(2023, "day6b").unwrap();
+        let ms = Duration::from_millis;
+        let stats = Stats { min: ms(1), median: ms(2), mean: ms(3) };
+        let report = Report {
+            answer: Answer::Int(1),
+            iterations: 5,
+            parse: None,
+            solve: stats,
+            total: stats,
+        };
+        let fields = report.csv_fields(solution, "test1");
+        assert_eq!(
+            fields,
+            "2023,day6b,default,6,b,\"test1\",5,,,,1000000,2000000,3000000,1000000,2000000,3000000"
+        );
+        assert_eq!(CSV_HEADER.split(',').count(), fields.split(',').count() + 1);
+        let fields = report.csv_fields(solution, "in/a,\"b\".txt");
+        assert!(fields.starts_with("2023,day6b,default,6,b,\"in/a,\"\"b\"\".txt\",5,"));
+    }
+
+    #[test]
+    fn test_append_csv() {
+        let solution = crate::commands::find(2023, "day6b").unwrap();
+        let ms = Duration::from_millis;
+        let stats = Stats { min: ms(1), median: ms(2), mean: ms(3) };
+        let report = Report {
+            answer: Answer::Int(1),
+            iterations: 5,
+            parse: None,
+            solve: stats,
+            total: stats,
+        };
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.csv", std::process::id()));
+
+        report.append_csv(&path, solution, "test1").unwrap();
+        report.append_csv(&path, solution, "test1").unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER);
+
+        // a file from before the year column was added
+        fs::write(&path, "timestamp,solution,variant\n1,day6b,default\n").unwrap();
+        let error = report.append_csv(&path, solution, "test1").unwrap_err();
+        assert!(error.to_string().contains("has the columns timestamp,solution,variant"));
+        assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 2);
+        fs::remove_file(&path).unwrap();
+    }
+}
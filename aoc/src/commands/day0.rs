@@ -1,23 +1,17 @@
 use clap::Parser;
-use std::fs;
-use std::path::PathBuf;
 
-use super::{CommandImpl, DynError};
+use super::{parse_at, Answer, CommandImpl, DynError, ParseError};
 
 #[derive(Parser, Debug)]
-pub struct Day0 {
-    #[clap(long, short)]
-    input: PathBuf,
-}
+pub struct Day0;
 
 impl CommandImpl for Day0 {
-    fn main(&self) -> Result<(), DynError> {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
         let mut result = 0;
 
-        for line in fs::read_to_string(&self.input).unwrap().lines() {
-            //do something
+        for line in input.lines() {
+            //do something, returning ParseError::at(input, line, ..) for bad input
         }
-        println!("Day0: {result}");
-        Ok(())
+        Ok(result.into())
     }
 }
@@ -1,37 +1,278 @@
-pub mod day0;
-pub mod day10a;
-pub mod day10b;
-pub mod day11a;
-pub mod day11b;
-pub mod day1a;
-pub mod day1b;
-pub mod day2a;
-pub mod day2b;
-pub mod day3a;
-pub mod day3b;
-pub mod day4a;
-pub mod day4b;
-pub mod day5a;
-pub mod day5b;
-pub mod day5b2;
-pub mod day6a;
-pub mod day6b;
-pub mod day7a;
-pub mod day7b;
-pub mod day8a;
-pub mod day8b;
-pub mod day8b_bruteforce;
-pub mod day8b_smartbf;
-pub mod day9a;
-pub mod day9b;
-
 use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use clap::builder::PossibleValuesParser;
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command, CommandFactory, FromArgMatches};
+
+pub use crate::answer::Answer;
+pub use crate::bench::mark_parsed;
+use crate::runner::{config, inputs_dir, read_input, Input};
+pub use crate::utils::{parse_at, records, split_once_at, ParseError};
+
+// `pub mod yYYYY { pub mod dayXX; }` declarations and `SOLUTIONS`, generated by build.rs from the
+// files in this directory
+include!(concat!(env!("OUT_DIR"), "/commands.rs"));
 
-use enum_dispatch::enum_dispatch;
+// rustfmt doesn't follow the modules declared in OUT_DIR, so it reaches the solution files
+// through this list instead. Never compiled: `cargo xtask new-day` adds each new day, and the build
+// script warns about files missing from it.
+#[cfg(rustfmt)]
+#[path = "."]
+mod rustfmt_modules {
+    #[path = "day0.rs"]
+    mod day0;
+    #[path = "y2023/day10.rs"]
+    mod y2023_day10;
+    #[path = "y2023/day10a.rs"]
+    mod y2023_day10a;
+    #[path = "y2023/day10b.rs"]
+    mod y2023_day10b;
+    #[path = "y2023/day11a.rs"]
+    mod y2023_day11a;
+    #[path = "y2023/day11b.rs"]
+    mod y2023_day11b;
+    #[path = "y2023/day1a.rs"]
+    mod y2023_day1a;
+    #[path = "y2023/day1b.rs"]
+    mod y2023_day1b;
+    #[path = "y2023/day2a.rs"]
+    mod y2023_day2a;
+    #[path = "y2023/day2b.rs"]
+    mod y2023_day2b;
+    #[path = "y2023/day3a.rs"]
+    mod y2023_day3a;
+    #[path = "y2023/day3b.rs"]
+    mod y2023_day3b;
+    #[path = "y2023/day4a.rs"]
+    mod y2023_day4a;
+    #[path = "y2023/day4b.rs"]
+    mod y2023_day4b;
+    #[path = "y2023/day5a.rs"]
+    mod y2023_day5a;
+    #[path = "y2023/day5b.rs"]
+    mod y2023_day5b;
+    #[path = "y2023/day5b2.rs"]
+    mod y2023_day5b2;
+    #[path = "y2023/day5b_maps.rs"]
+    mod y2023_day5b_maps;
+    #[path = "y2023/day6a.rs"]
+    mod y2023_day6a;
+    #[path = "y2023/day6b.rs"]
+    mod y2023_day6b;
+    #[path = "y2023/day7a.rs"]
+    mod y2023_day7a;
+    #[path = "y2023/day7b.rs"]
+    mod y2023_day7b;
+    #[path = "y2023/day8a.rs"]
+    mod y2023_day8a;
+    #[path = "y2023/day8b.rs"]
+    mod y2023_day8b;
+    #[path = "y2023/day8b_bruteforce.rs"]
+    mod y2023_day8b_bruteforce;
+    #[path = "y2023/day8b_smartbf.rs"]
+    mod y2023_day8b_smartbf;
+    #[path = "y2023/day9a.rs"]
+    mod y2023_day9a;
+    #[path = "y2023/day9b.rs"]
+    mod y2023_day9b;
+}
 
 pub type DynError = Box<dyn Error + 'static>;
 
-#[enum_dispatch]
 pub trait CommandImpl {
-    fn main(&self) -> Result<(), DynError>;
+    /// Name of the approach for `--variant`, otherwise taken from the file name: `smartbf` for
+    /// day8b_smartbf.rs, `default` without a suffix
+    const VARIANT: Option<&'static str> = None;
+    /// Too slow to check against the challenge inputs on every `cargo test`, and skipped by
+    /// `--all-variants` unless `--include-slow` is given
+    const SLOW: bool = false;
+
+    /// Solve the puzzle for the content of the input file
+    fn main(&self, input: &str) -> Result<Answer, DynError>;
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" => Ok(Part::A),
+            "b" => Ok(Part::B),
+            other => Err(format!("Unknown part {other}, expected a or b")),
+        }
+    }
+}
+
+/// A registered solution for one part of a day. All variants of a part share one subcommand and
+/// are selected with `--variant`.
+pub struct Solution {
+    pub year: u32,
+    /// Subcommand name, e.g. `day8b`
+    pub name: &'static str,
+    /// Name of the approach, e.g. `smartbf`
+    pub variant: &'static str,
+    /// Whether this variant runs when no `--variant` is given
+    pub is_default: bool,
+    /// See `CommandImpl::SLOW`
+    pub slow: bool,
+    pub day: u32,
+    pub part: Part,
+    command: fn() -> Command,
+    run: fn(&ArgMatches, &str) -> Result<Answer, DynError>,
+}
+
+impl Solution {
+    // `file_variant` is the variant named by the file, used unless the command sets its own
+    const fn new<T>(
+        year: u32,
+        name: &'static str,
+        file_variant: &'static str,
+        is_default: bool,
+        day: u32,
+        part: Part,
+    ) -> Self
+    where
+        T: CommandImpl + CommandFactory + FromArgMatches,
+    {
+        let variant = match T::VARIANT {
+            Some(variant) => variant,
+            None => file_variant,
+        };
+        Solution {
+            year,
+            name,
+            variant,
+            is_default,
+            slow: T::SLOW,
+            day,
+            part,
+            command: T::command,
+            run: run::<T>,
+        }
+    }
+
+    /// The subcommand for this part, taking the options of all its variants
+    pub fn command(&self) -> Command {
+        let mut cmd = (self.command)();
+        for other in self.variants().filter(|other| other.variant != self.variant) {
+            for arg in (other.command)().get_arguments() {
+                if cmd.get_arguments().all(|existing| existing.get_id() != arg.get_id()) {
+                    cmd = cmd.arg(arg.clone());
+                }
+            }
+        }
+
+        let default = self.variants().find(|variant| variant.is_default).unwrap_or(self);
+        let variant = Arg::new("variant")
+            .long("variant")
+            .value_name("VARIANT")
+            .value_parser(PossibleValuesParser::new(self.variants().map(|other| other.variant)))
+            .default_value(default.variant)
+            .help("Approach to solve the puzzle with");
+        let all_variants = Arg::new("all-variants")
+            .long("all-variants")
+            .action(ArgAction::SetTrue)
+            .conflicts_with_all(["variant", "bench"])
+            .help("Run every variant and check that they agree on the answer");
+        let include_slow = Arg::new("include-slow")
+            .long("include-slow")
+            .action(ArgAction::SetTrue)
+            .requires("all-variants")
+            .help("Also run the variants that are too slow for --all-variants by default");
+        let inputs_dir = inputs_dir(self.year, self.day);
+        let inputs_dir = inputs_dir.strip_prefix(&config().root).unwrap_or(&inputs_dir);
+        let input = Arg::new("input").long("input").short('i').value_name("INPUT").help(format!(
+            "Input file, the name of a file in {} such as test1, or - for stdin \
+            [default: stdin when piped, else challenge1]",
+            inputs_dir.display()
+        ));
+        let bench = Arg::new("bench")
+            .long("bench")
+            .value_name("N")
+            .value_parser(value_parser!(usize))
+            .help("Run N times and report min/median/mean of parse and solve time");
+        let csv = Arg::new("csv")
+            .long("csv")
+            .value_name("FILE")
+            .value_parser(value_parser!(PathBuf))
+            .requires("bench")
+            .help("Append the benchmark results to a CSV file");
+        cmd.name(self.name).args([input, bench, csv, variant, all_variants, include_slow])
+    }
+
+    /// All variants of this solution's part, including itself
+    pub fn variants(&self) -> impl Iterator<Item = &'static Solution> + '_ {
+        SOLUTIONS
+            .iter()
+            .filter(|other| (other.year, other.day, other.part) == (self.year, self.day, self.part))
+    }
+
+    /// The variant selected with `--variant`
+    pub fn selected(&self, matches: &ArgMatches) -> &'static Solution {
+        let variant = matches.get_one::<String>("variant").expect("Variant has a default");
+        self.variants().find(|other| other.variant == variant).expect("Variant is validated")
+    }
+
+    /// Run on the given input file with default options
+    pub fn run_with_input(&self, input: &Path) -> Result<Answer, DynError> {
+        let matches = self.command().try_get_matches_from([self.name])?;
+        self.solve(&matches, &Input::file(input)?)
+    }
+
+    /// Read the input selected by `--input` on the command line
+    pub fn input(&self, matches: &ArgMatches) -> Result<Input, DynError> {
+        read_input(self.year, self.day, matches.get_one::<String>("input").map(String::as_str))
+    }
+
+    /// Solve for an input that was already read, with the options from `matches`. Parse errors
+    /// are told which file they point into.
+    pub fn solve(&self, matches: &ArgMatches, input: &Input) -> Result<Answer, DynError> {
+        (self.run)(matches, &input.content).map_err(|e| match e.downcast::<ParseError>() {
+            Ok(e) => e.with_file(input.source()).into(),
+            Err(e) => e,
+        })
+    }
+
+    /// Label used when reporting the answer, e.g. `Day8b` (shared by all variants of a part)
+    pub fn label(&self) -> String {
+        format!("Day{}{}", self.day, self.part)
+    }
+}
+
+fn run<T>(matches: &ArgMatches, input: &str) -> Result<Answer, DynError>
+where
+    T: CommandImpl + FromArgMatches,
+{
+    T::from_arg_matches(matches)?.main(input)
+}
+
+/// The default variant of the solution with this subcommand name in `year`
+pub fn find(year: u32, name: &str) -> Option<&'static Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.year == year && solution.name == name && solution.is_default)
+}
+
+/// Years with at least one solution, in order
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<u32> = SOLUTIONS.iter().map(|solution| solution.year).collect();
+    years.dedup();
+    years
 }
@@ -0,0 +1,190 @@
+// The pipe field both parts of day 10 walk, not a solution itself
+
+use core::fmt;
+use std::collections::HashMap;
+
+use super::ParseError;
+use crate::utils::{
+    geometry::{Direction, Point},
+    graph::bfs,
+    grid::Grid,
+};
+
+#[derive(Debug)]
+pub struct Field {
+    pub pipes: Grid<Pipe>,
+    pub start: Point,
+}
+
+impl Field {
+    pub fn from_input(input: &str) -> Result<Self, ParseError> {
+        let pipes = Grid::parse(input, Pipe::from_char)?;
+        let start = pipes
+            .find(|&pipe| pipe == Pipe::Start)
+            .ok_or_else(|| ParseError::new("No start S in the field"))?;
+        let mut field = Field { pipes, start };
+        // S stands for the pipe that closes the loop, anything else pointing into it is not on it
+        field.pipes[start] = field.start_pipe()?;
+        Ok(field)
+    }
+
+    // the pipe under S: the one joining the two directions in which the pipes lead back to S
+    fn start_pipe(&self) -> Result<Pipe, ParseError> {
+        let directions: Vec<Direction> = Direction::CARDINAL
+            .into_iter()
+            .filter(|&direction| self.leads_back_to_start(direction))
+            .collect();
+        Pipe::from_directions(&directions)
+            .ok_or_else(|| ParseError::new("S has to join exactly two pipes of a loop"))
+    }
+
+    // follows the pipes leaving S towards `direction` until they end or reach S again
+    fn leads_back_to_start(&self, mut direction: Direction) -> bool {
+        let mut position = self.start;
+        loop {
+            let Some(next) = self.pipes.step(position, direction) else {
+                return false;
+            };
+            if next == self.start {
+                return true;
+            }
+            let Some(directions) = self.pipes[next].directions() else {
+                return false;
+            };
+            let entry = direction.reverse();
+            if !directions.contains(&entry) {
+                return false;
+            }
+            direction = *directions.iter().find(|&&d| d != entry).expect("Pipes have two ends");
+            position = next;
+        }
+    }
+
+    // the pipes that connect to the one at `position`, both ways
+    pub fn connections(&self, position: Point) -> Vec<Point> {
+        let directions = self.pipes[position].directions().unwrap_or_default();
+        directions
+            .into_iter()
+            .filter_map(|direction| {
+                let next = self.pipes.step(position, direction)?;
+                let back = self.pipes[next].directions()?;
+                back.contains(&direction.reverse()).then_some(next)
+            })
+            .collect()
+    }
+
+    // steps from the start to every pipe of the loop through it
+    pub fn loop_distances(&self) -> HashMap<Point, usize> {
+        bfs(self.start, |&position| self.connections(position))
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.pipes)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Pipe {
+    None,
+    NS,
+    EW,
+    NE,
+    ES,
+    SW,
+    WN,
+    Start,
+    // part b's marking for tiles reachable from outside the loop
+    Outside,
+}
+
+impl Pipe {
+    fn directions(&self) -> Option<Vec<Direction>> {
+        use Direction::{E, N, S, W};
+        use Pipe::*;
+        match self {
+            None => Option::None,
+            NS => Some(vec![N, S]),
+            EW => Some(vec![E, W]),
+            NE => Some(vec![N, E]),
+            ES => Some(vec![E, S]),
+            SW => Some(vec![S, W]),
+            WN => Some(vec![W, N]),
+            Start => Some(Direction::CARDINAL.to_vec()),
+            Outside => Option::None,
+        }
+    }
+
+    // the pipe connecting two directions given in `Direction::CARDINAL` order
+    fn from_directions(directions: &[Direction]) -> Option<Self> {
+        use Direction::{E, N, S, W};
+        match directions {
+            [N, S] => Some(Pipe::NS),
+            [E, W] => Some(Pipe::EW),
+            [N, E] => Some(Pipe::NE),
+            [E, S] => Some(Pipe::ES),
+            [S, W] => Some(Pipe::SW),
+            [N, W] => Some(Pipe::WN),
+            _ => Option::None,
+        }
+    }
+
+    fn from_char(c: char) -> Result<Self, String> {
+        match c {
+            '.' => Ok(Pipe::None),
+            'S' => Ok(Pipe::Start),
+            '|' => Ok(Pipe::NS),
+            '-' => Ok(Pipe::EW),
+            'L' => Ok(Pipe::NE),
+            'J' => Ok(Pipe::WN),
+            '7' => Ok(Pipe::SW),
+            'F' => Ok(Pipe::ES),
+            'O' => Ok(Pipe::Outside),
+            other => Err(format!("Unaware of char {other}")),
+        }
+    }
+
+    fn as_char(&self) -> char {
+        use Pipe::*;
+        match self {
+            None => '.',
+            Start => 'S',
+            NS => '|',
+            EW => '-',
+            NE => 'L',
+            WN => 'J',
+            SW => '7',
+            ES => 'F',
+            Outside => 'O',
+        }
+    }
+}
+
+impl fmt::Display for Pipe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_shape() {
+        // the pipes above S point into it but dead-end at the top, only F and J close the loop
+        let field = Field::from_input(".|...\n.|...\n.|...\n.|...\n.|...\n.S-7.\n.|.|.\n.L-J.\n");
+        let field = field.unwrap();
+        assert_eq!(field.pipes[field.start], Pipe::ES);
+        assert_eq!(field.loop_distances().len(), 8);
+
+        // the | below S points into it but isn't on the loop
+        let field = Field::from_input(".......\n.F-S-7.\n.|.|.|.\n.|...|.\n.L---J.\n.......\n");
+        let field = field.unwrap();
+        assert_eq!(field.pipes[field.start], Pipe::EW);
+        assert!(!field.loop_distances().contains_key(&Point::new(3, 2)));
+
+        assert!(Field::from_input("...\n.S.\n...\n").is_err());
+    }
+}
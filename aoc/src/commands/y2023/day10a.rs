@@ -0,0 +1,34 @@
+use clap::Parser;
+use log::debug;
+
+use super::day10::Field;
+use super::{mark_parsed, Answer, CommandImpl, DynError};
+
+#[derive(Parser, Debug)]
+pub struct Day10a;
+
+impl CommandImpl for Day10a {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let field = Field::from_input(input)?;
+        mark_parsed();
+        let distances = field.loop_distances();
+        let (farthest, &result) =
+            distances.iter().max_by_key(|(_, &distance)| distance).expect("The start is reached");
+        debug!("Length of circle is {}, max distance is thus {result}", distances.len());
+        debug!("Max distance is at X: {}, Y: {}", farthest.x, farthest.y);
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the pipes above S point into it but dead-end at the top, only F and J close the loop
+    const TAIL_INTO_START: &str = ".|...\n.|...\n.|...\n.|...\n.|...\n.S-7.\n.|.|.\n.L-J.\n";
+
+    #[test]
+    fn test_start_shape() {
+        assert_eq!(Day10a.main(TAIL_INTO_START).unwrap(), Answer::Int(4));
+    }
+}
@@ -0,0 +1,146 @@
+use clap::Parser;
+use log::{debug, trace};
+use std::collections::HashSet;
+
+use super::day10::{Field, Pipe};
+use super::{mark_parsed, Answer, CommandImpl, DynError};
+use crate::utils::{geometry::Point, graph::flood_fill, grid::Grid};
+
+#[derive(Parser, Debug)]
+pub struct Day10b;
+
+//idea to solve B:
+// 1. Blow up the field 2x (adding to bottom and right), and add a first row top and left
+//    this should ensure that all fields that are outside are connected
+// 2. Find a field that is definitely outside (e.g. position < min_position pipes)
+// 3. Walk to all fields possible
+//    -> mark all positions that touch the first, then for each new marked to the same
+// 4. To count only fields present at the start, look at only every 2nd (+1)
+/*
+
+-> change all tiles not part of the loop to .
+
+.F7..
+.||..
+.|L-7
+.L--J
+
+-> blow up in x direction
+
+..F-7.....
+..|.|.....
+..|.L---7.
+..L-----J.
+
+-> blow up in y direction
+
+..F-7.....
+..|.|.....
+..|.|.....
+..|.|.....
+..|.L---7.
+..|.....|.
+..L-----J.
+..........
+
+-> add top and left border
+...........
+...F-7.....
+...|.|.....
+...|.|.....
+...|.|.....
+...|.L---7.
+...|.....|.
+...L-----J.
+...........
+
+-> mark all outside
+
+O..........
+...F-7.....
+...|.|.....
+...|.|.....
+...|.|.....
+...|.L---7.
+...|.....|.
+...L-----J.
+...........
+
+-> count all non-marked ().) that are at (x%2 == 1 & y%2 == 1)
+*/
+
+impl CommandImpl for Day10b {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let field = Field::from_input(input)?;
+        mark_parsed();
+        let circle: HashSet<Point> = field.loop_distances().into_keys().collect();
+        debug!("Length of circle is {}", circle.len());
+        trace!("Field:\n{field}");
+        let exploded_field = field.expand(&circle);
+        trace!("Expanded field:\n{exploded_field}");
+        let exploded_field = mark_outside_pipes(exploded_field);
+        trace!("Marked field:\n{exploded_field}");
+        let count = count_pre_expansion_dots(&exploded_field);
+        Ok(count.into())
+    }
+}
+
+fn mark_outside_pipes(mut field: Field) -> Field {
+    // start at 0,0 (we know this one cannot be inside the pipes)
+    let outside = flood_fill(Point::new(0, 0), |&position| {
+        field
+            .pipes
+            .neighbours4(position)
+            .filter(|&next| field.pipes[next] == Pipe::None)
+            .collect::<Vec<_>>()
+    });
+    for position in outside {
+        field.pipes[position] = Pipe::Outside;
+    }
+    field
+}
+
+fn count_pre_expansion_dots(field: &Field) -> usize {
+    let mut count = 0;
+
+    for y in 0..(field.pipes.height() / 2) {
+        for x in 0..(field.pipes.width() / 2) {
+            if field.pipes[Point::new(x as i64 * 2 + 1, y as i64 * 2 + 1)] == Pipe::None {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+impl Field {
+    fn expand(&self, circle: &HashSet<Point>) -> Self {
+        //first step: set all fields that aren't part of the path to None
+        let mut field =
+            Grid::new(self.pipes.width() * 2 + 1, self.pipes.height() * 2 + 1, Pipe::None);
+        //second step: add in circular path, each pipe with its connections to the next ones
+        let expanded = |position: Point| position * 2 + Point::new(1, 1);
+        for &position in circle {
+            field[expanded(position)] = self.pipes[position];
+            for next in self.connections(position) {
+                let direction = next - position;
+                let connection = if direction.x == 0 { Pipe::NS } else { Pipe::EW };
+                field[expanded(position) + direction] = connection;
+            }
+        }
+        Field { pipes: field, start: expanded(self.start) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the | below S points into it but isn't on the loop, so it is one of the 6 enclosed tiles
+    const PIPE_INTO_START: &str = ".......\n.F-S-7.\n.|.|.|.\n.|...|.\n.L---J.\n.......\n";
+
+    #[test]
+    fn test_start_shape() {
+        assert_eq!(Day10b.main(PIPE_INTO_START).unwrap(), Answer::Int(6));
+    }
+}
@@ -0,0 +1,106 @@
+use super::{mark_parsed, Answer, CommandImpl, DynError, ParseError};
+use crate::utils::{geometry::Point, grid::Grid};
+use clap::Parser;
+use core::fmt;
+use log::{debug, trace};
+
+#[derive(Parser, Debug)]
+pub struct Day11a;
+
+impl CommandImpl for Day11a {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let mut universe = Universe::create(input)?;
+        mark_parsed();
+        trace!("Universe:\n{universe}");
+        universe.expand();
+        trace!("Expanded universe:\n{universe}");
+        let result = universe.get_total_distances();
+        Ok(result.into())
+    }
+}
+#[derive(Debug)]
+struct Galaxy {
+    id: usize,
+    location: Point,
+}
+
+#[derive(Debug)]
+struct Universe {
+    galaxies: Vec<Galaxy>,
+    width: usize,
+    height: usize,
+    empty_rows: Vec<i64>,
+    empty_columns: Vec<i64>,
+}
+
+impl fmt::Display for Universe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut image = Grid::new(self.width, self.height, '.');
+        for galaxy in &self.galaxies {
+            image[galaxy.location] = '#';
+        }
+        writeln!(f, "{image}")
+    }
+}
+
+impl Universe {
+    fn create(input: &str) -> Result<Self, ParseError> {
+        let image = Grid::parse(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            other => Err(format!("Expected . or #, found {other}")),
+        })?;
+        let galaxies: Vec<Galaxy> = image
+            .iter()
+            .filter(|(_, &is_galaxy)| is_galaxy)
+            .enumerate()
+            .map(|(id, (location, _))| Galaxy { id, location })
+            .collect();
+        let empty_rows = empty_indices(image.rows().map(|row| row.iter().any(|&cell| cell)));
+        let empty_columns =
+            empty_indices(image.columns().map(|mut column| column.any(|&cell| cell)));
+        debug!(
+            "Universe has {} galaxies within {}x{}",
+            galaxies.len(),
+            image.width(),
+            image.height()
+        );
+        Ok(Universe {
+            galaxies,
+            width: image.width(),
+            height: image.height(),
+            empty_rows,
+            empty_columns,
+        })
+    }
+
+    fn expand(&mut self) {
+        debug!("Empty columns: {:?}, empty rows: {:?}", self.empty_columns, self.empty_rows);
+        for galaxy in self.galaxies.iter_mut() {
+            let location = galaxy.location;
+            let columns_before = self.empty_columns.iter().filter(|&&x| x < location.x).count();
+            let rows_before = self.empty_rows.iter().filter(|&&y| y < location.y).count();
+            galaxy.location.x += columns_before as i64;
+            galaxy.location.y += rows_before as i64;
+        }
+        self.width += self.empty_columns.len();
+        self.height += self.empty_rows.len();
+    }
+
+    fn get_total_distances(&self) -> u64 {
+        let mut total_distance = 0;
+        for galaxy1 in &self.galaxies {
+            for galaxy2 in &self.galaxies {
+                if galaxy1.id < galaxy2.id {
+                    total_distance += galaxy1.location.manhattan(galaxy2.location);
+                }
+            }
+        }
+        total_distance
+    }
+}
+
+// indices of the rows or columns without a galaxy
+fn empty_indices(has_galaxy: impl Iterator<Item = bool>) -> Vec<i64> {
+    has_galaxy.enumerate().filter(|&(_, has_galaxy)| !has_galaxy).map(|(i, _)| i as i64).collect()
+}
@@ -1,32 +1,28 @@
 use clap::Parser;
-use std::fs;
-use std::path::PathBuf;
+use log::trace;
 
-use super::{CommandImpl, DynError};
+use super::{Answer, CommandImpl, DynError, ParseError};
 
 use std::collections::HashMap;
 
 #[derive(Parser, Debug)]
-pub struct Day1a {
-    #[clap(long, short)]
-    input: PathBuf,
-}
+pub struct Day1a;
 
 impl CommandImpl for Day1a {
-    fn main(&self) -> Result<(), DynError> {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
         let mut total_sum = 0;
 
-        for line in fs::read_to_string(&self.input).unwrap().lines() {
-            let (first, last) = find_first_and_last_int(line);
-            println!("{first} {last}");
+        for line in input.lines() {
+            let (first, last) = find_first_and_last_int(line)
+                .ok_or_else(|| ParseError::at(input, line, "No digit in line"))?;
+            trace!("{line}: first {first}, last {last}");
             total_sum += 10 * first + last;
         }
-        println!("Day1a: {total_sum}");
-        Ok(())
+        Ok(total_sum.into())
     }
 }
 
-fn find_first_and_last_int(line: &str) -> (i32, i32) {
+fn find_first_and_last_int(line: &str) -> Option<(i32, i32)> {
     let mut first: i32 = -1;
     let mut found_first = false;
     let mut last: i32 = -1;
@@ -40,5 +36,5 @@ fn find_first_and_last_int(line: &str) -> (i32, i32) {
             last = char.to_digit(10).unwrap() as i32;
         }
     }
-    (first, last)
+    found_first.then_some((first, last))
 }
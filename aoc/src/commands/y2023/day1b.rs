@@ -1,57 +1,48 @@
 use clap::Parser;
-use std::fs;
-use std::path::PathBuf;
+use log::trace;
 
-use super::{CommandImpl, DynError};
+use super::{Answer, CommandImpl, DynError, ParseError};
 
 use std::collections::HashMap;
 
 #[derive(Parser, Debug)]
-pub struct Day1b {
-    #[clap(long, short)]
-    input: PathBuf,
-}
+pub struct Day1b;
 
 impl CommandImpl for Day1b {
-    fn main(&self) -> Result<(), DynError> {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
         let mut total_sum = 0;
 
-        for line in fs::read_to_string(&self.input).unwrap().lines() {
-            let (first, last) = find_first_and_last_int(line);
-            println!("{first} {last}");
+        for line in input.lines() {
+            let (first, last) = find_first_and_last_int(line)
+                .ok_or_else(|| ParseError::at(input, line, "No digit in line"))?;
+            trace!("{line}: first {first}, last {last}");
             total_sum += 10 * first + last;
         }
-        println!("Day1b: {total_sum}");
-        Ok(())
+        Ok(total_sum.into())
     }
 }
 
-fn find_first_and_last_int(line: &str) -> (i32, i32) {
+fn find_first_and_last_int(line: &str) -> Option<(i32, i32)> {
     let mut first: i32 = -1;
     let mut found_first = false;
     let mut last: i32 = -1;
 
-    for (i, char) in line.chars().enumerate() {
+    for (i, char) in line.char_indices() {
         if char.is_ascii_digit() {
             if !found_first {
                 first = char.to_digit(10).unwrap() as i32;
                 found_first = true;
             }
             last = char.to_digit(10).unwrap() as i32;
-        } else {
-            match convert_str_to_number(&line[i..]) {
-                Some(i) => {
-                    if !found_first {
-                        first = i;
-                        found_first = true;
-                    }
-                    last = i;
-                }
-                None => {}
+        } else if let Some(i) = convert_str_to_number(&line[i..]) {
+            if !found_first {
+                first = i;
+                found_first = true;
             }
+            last = i;
         }
     }
-    (first, last)
+    found_first.then_some((first, last))
 }
 
 fn convert_str_to_number(line: &str) -> Option<i32> {
@@ -70,10 +61,8 @@ fn convert_str_to_number(line: &str) -> Option<i32> {
 
     for (key, value) in str_to_int {
         let length = key.len();
-        if length <= line.len() {
-            if key == &line[..length] {
-                return Some(value);
-            }
+        if length <= line.len() && key == &line[..length] {
+            return Some(value);
         }
     }
     None
@@ -0,0 +1,55 @@
+use clap::Parser;
+use log::{trace, warn};
+use std::collections::HashMap;
+
+use super::{parse_at, split_once_at, Answer, CommandImpl, DynError, ParseError};
+
+#[derive(Parser, Debug)]
+pub struct Day2a;
+
+impl CommandImpl for Day2a {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let mut total_sum = 0;
+
+        for line in input.lines() {
+            total_sum += get_game_id_if_possible(input, line)?;
+        }
+        Ok(total_sum.into())
+    }
+}
+
+fn get_game_id_if_possible(input: &str, line: &str) -> Result<i32, ParseError> {
+    let max_limits: HashMap<&str, i32> = HashMap::from([("red", 12), ("green", 13), ("blue", 14)]);
+    let mut game_possible = true;
+
+    let (game, draws) = split_once_at(input, line, ": ")?;
+    let game_id: i32 = match game.strip_prefix("Game ") {
+        Some(id) => parse_at(input, id)?,
+        None => return Err(ParseError::at(input, game, "Expected \"Game <id>\"")),
+    };
+
+    for draw in draws.split("; ") {
+        for num_color in draw.split(", ") {
+            let (num, color) = split_once_at(input, num_color, " ")?;
+            let num: i32 = parse_at(input, num)?;
+
+            match max_limits.get(color) {
+                Some(limit) => {
+                    if limit < &num {
+                        game_possible = false;
+                    }
+                }
+                None => {
+                    warn!("Could not find color {color} in max_limit dict")
+                }
+            }
+        }
+    }
+    if game_possible {
+        trace!("Game {game_id} is possible");
+        Ok(game_id)
+    } else {
+        trace!("Game {game_id} is not possible");
+        Ok(0)
+    }
+}
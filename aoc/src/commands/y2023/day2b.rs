@@ -0,0 +1,55 @@
+use clap::Parser;
+use log::{trace, warn};
+use std::collections::HashMap;
+
+use super::{parse_at, split_once_at, Answer, CommandImpl, DynError, ParseError};
+
+#[derive(Parser, Debug)]
+pub struct Day2b;
+
+impl CommandImpl for Day2b {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let mut total_sum = 0;
+
+        for line in input.lines() {
+            let game_id = find_fewest_possible(input, line)?;
+            total_sum += game_id;
+        }
+        Ok(total_sum.into())
+    }
+}
+
+fn find_fewest_possible(input: &str, line: &str) -> Result<i32, ParseError> {
+    let mut min_limits: HashMap<&str, i32> = HashMap::from([("red", 0), ("green", 0), ("blue", 0)]);
+
+    let (game, draws) = split_once_at(input, line, ": ")?;
+    let game_id: i32 = match game.strip_prefix("Game ") {
+        Some(id) => parse_at(input, id)?,
+        None => return Err(ParseError::at(input, game, "Expected \"Game <id>\"")),
+    };
+
+    for draw in draws.split("; ") {
+        for num_color in draw.split(", ") {
+            let (num, color) = split_once_at(input, num_color, " ")?;
+            let num: i32 = parse_at(input, num)?;
+
+            match min_limits.get(color) {
+                Some(limit) => {
+                    if limit < &num {
+                        trace!("Game {game_id} needs at least {num} {color}");
+                        *min_limits.get_mut(color).unwrap() = num;
+                    }
+                }
+                None => {
+                    warn!("Could not find color {color} in min_limit dict")
+                }
+            }
+        }
+    }
+    let mut power = 1;
+    for (color, num) in min_limits {
+        power *= num;
+    }
+
+    Ok(power)
+}
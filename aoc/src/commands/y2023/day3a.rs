@@ -0,0 +1,86 @@
+use clap::Parser;
+use log::{debug, trace};
+
+use super::{mark_parsed, Answer, CommandImpl, DynError, ParseError};
+use crate::utils::{geometry::Point, grid::Grid};
+
+#[derive(Parser, Debug)]
+pub struct Day3a;
+
+struct Number {
+    value: usize,
+    /// Point of the first digit
+    start: Point,
+    len: i64,
+}
+
+impl Number {
+    fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.len).map(|i| Point::new(self.start.x + i, self.start.y))
+    }
+}
+
+impl CommandImpl for Day3a {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let schematic = Grid::parse_chars(input)?;
+        let numbers = find_numbers(&schematic)?;
+
+        mark_parsed();
+        let result = check_number_for_symbol(&schematic, &numbers);
+        Ok(result.into())
+    }
+}
+
+// every number in the schematic, row by row
+fn find_numbers(schematic: &Grid<char>) -> Result<Vec<Number>, ParseError> {
+    let mut numbers: Vec<Number> = Vec::new();
+    for (y, row) in schematic.rows().enumerate() {
+        let mut current: Option<Number> = None;
+        for (x, c) in row.iter().enumerate() {
+            match (c.to_digit(10), current.as_mut()) {
+                (Some(digit), Some(number)) => {
+                    number.value = number
+                        .value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(digit as usize))
+                        .ok_or_else(|| {
+                            ParseError::new(format!(
+                                "Number in row {} column {} is too large",
+                                y + 1,
+                                number.start.x + 1
+                            ))
+                        })?;
+                    number.len += 1;
+                }
+                (Some(digit), None) => {
+                    let start = Point::new(x as i64, y as i64);
+                    current = Some(Number { value: digit as usize, start, len: 1 });
+                }
+                (None, _) => numbers.extend(current.take()),
+            }
+        }
+        numbers.extend(current);
+    }
+    Ok(numbers)
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+fn check_number_for_symbol(schematic: &Grid<char>, numbers: &[Number]) -> usize {
+    let mut total_sum: usize = 0;
+    debug!("Total number of rows: {}", schematic.height());
+    for number in numbers {
+        trace!("Checking number {} at {}", number.value, number.start);
+        let contacts_symbol = number
+            .points()
+            .flat_map(|point| schematic.neighbours8(point))
+            .any(|neighbour| is_symbol(schematic[neighbour]));
+        if contacts_symbol {
+            trace!("Number {} at {} is next to a symbol", number.value, number.start);
+            total_sum += number.value;
+        }
+    }
+    total_sum
+}
@@ -0,0 +1,100 @@
+use clap::Parser;
+use log::{debug, trace};
+
+use super::{mark_parsed, Answer, CommandImpl, DynError, ParseError};
+use crate::utils::{geometry::Point, grid::Grid};
+
+#[derive(Parser, Debug)]
+pub struct Day3b;
+
+struct Number {
+    value: usize,
+    /// Point of the first digit
+    start: Point,
+    len: i64,
+}
+
+impl Number {
+    fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.len).map(|i| Point::new(self.start.x + i, self.start.y))
+    }
+
+    // whether `point` is next to one of the digits, including diagonally
+    fn touches(&self, point: Point) -> bool {
+        (point.y - self.start.y).abs() <= 1
+            && point.x >= self.start.x - 1
+            && point.x <= self.start.x + self.len
+    }
+}
+
+impl CommandImpl for Day3b {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let schematic = Grid::parse_chars(input)?;
+        let numbers = find_numbers(&schematic)?;
+
+        mark_parsed();
+        let result = find_gear_ratio(&schematic, &numbers);
+        Ok(result.into())
+    }
+}
+
+// every number in the schematic, row by row
+fn find_numbers(schematic: &Grid<char>) -> Result<Vec<Number>, ParseError> {
+    let mut numbers: Vec<Number> = Vec::new();
+    for (y, row) in schematic.rows().enumerate() {
+        let mut current: Option<Number> = None;
+        for (x, c) in row.iter().enumerate() {
+            match (c.to_digit(10), current.as_mut()) {
+                (Some(digit), Some(number)) => {
+                    number.value = number
+                        .value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(digit as usize))
+                        .ok_or_else(|| {
+                            ParseError::new(format!(
+                                "Number in row {} column {} is too large",
+                                y + 1,
+                                number.start.x + 1
+                            ))
+                        })?;
+                    number.len += 1;
+                }
+                (Some(digit), None) => {
+                    let start = Point::new(x as i64, y as i64);
+                    current = Some(Number { value: digit as usize, start, len: 1 });
+                }
+                (None, _) => numbers.extend(current.take()),
+            }
+        }
+        numbers.extend(current);
+    }
+    Ok(numbers)
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+fn find_gear_ratio(schematic: &Grid<char>, numbers: &[Number]) -> usize {
+    let mut total_sum: usize = 0;
+    for (point, &symbol) in schematic.iter().filter(|(_, &c)| is_symbol(c)) {
+        trace!("Checking symbol {symbol} at {point}");
+        if symbol == '*' {
+            trace!("Found gear");
+            let contacting_nums: Vec<usize> = numbers
+                .iter()
+                .filter(|number| number.touches(point))
+                .map(|number| number.value)
+                .collect();
+            match contacting_nums.len() {
+                0 | 1 => trace!("No two numbers matching"),
+                2 => {
+                    trace!("Found two numbers");
+                    total_sum += contacting_nums[0] * contacting_nums[1];
+                }
+                _ => debug!("More than 2 numbers contacting the gear"),
+            }
+        }
+    }
+    total_sum
+}
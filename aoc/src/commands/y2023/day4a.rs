@@ -0,0 +1,61 @@
+use clap::Parser;
+use log::trace;
+use regex::Regex;
+use std::collections::HashSet;
+
+use super::{parse_at, split_once_at, Answer, CommandImpl, DynError, ParseError};
+
+#[derive(Parser, Debug)]
+pub struct Day4a;
+
+impl CommandImpl for Day4a {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let mut result = 0;
+
+        for line in input.lines() {
+            let (card_id, num_matches) = get_num_matching(input, line)?;
+            let score = calc_score(num_matches);
+            result += score;
+        }
+        Ok(result.into())
+    }
+}
+
+fn get_card_id(input: &str, line: &str) -> Result<usize, ParseError> {
+    let re = Regex::new(r"Card\s*([0-9]+):").unwrap();
+    let id = re
+        .captures(line)
+        .and_then(|captures| captures.get(1))
+        .ok_or_else(|| ParseError::at(input, line, "Expected \"Card <id>:\""))?;
+    parse_at(input, id.as_str())
+}
+
+fn parse_numbers(input: &str, numbers: &str) -> Result<HashSet<u8>, ParseError> {
+    numbers.split(' ').filter(|x| !x.is_empty()).map(|x| parse_at(input, x)).collect()
+}
+
+fn get_num_matching(input: &str, line: &str) -> Result<(usize, usize), ParseError> {
+    let card_id = get_card_id(input, line)?;
+    let (_, numbers) = split_once_at(input, line, ": ")?;
+    let (picked, winning) = split_once_at(input, numbers, " | ")?;
+    trace!("Card {card_id}: picked {picked}, winning {winning}");
+    let picked_numbers = parse_numbers(input, picked)?;
+    let winning_numbers = parse_numbers(input, winning)?;
+    let mut matching_numbers: usize = 0;
+    for number in picked_numbers {
+        if winning_numbers.contains(&number) {
+            trace!("Number {number} is in winning set");
+            matching_numbers += 1
+        }
+    }
+
+    Ok((card_id, matching_numbers))
+}
+
+fn calc_score(num_matching: usize) -> usize {
+    if num_matching > 0 {
+        2_i32.pow(num_matching as u32 - 1_u32) as usize
+    } else {
+        0
+    }
+}
@@ -0,0 +1,70 @@
+use clap::Parser;
+use log::{debug, trace};
+use regex::Regex;
+use std::collections::HashSet;
+
+use super::{mark_parsed, parse_at, split_once_at, Answer, CommandImpl, DynError, ParseError};
+
+#[derive(Parser, Debug)]
+pub struct Day4b;
+
+impl CommandImpl for Day4b {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let mut result = 0;
+        let mut num_matches_per_game: Vec<usize> = Vec::new();
+        for line in input.lines() {
+            let (card_id, num_matches) = get_num_matching(input, line)?;
+            num_matches_per_game.push(num_matches);
+        }
+        if num_matches_per_game.is_empty() {
+            return Err(ParseError::new("No cards in input").into());
+        }
+        mark_parsed();
+        let score = calc_full_score(num_matches_per_game);
+        Ok(score.into())
+    }
+}
+
+fn get_card_id(input: &str, line: &str) -> Result<usize, ParseError> {
+    let re = Regex::new(r"Card\s*([0-9]+):").unwrap();
+    let id = re
+        .captures(line)
+        .and_then(|captures| captures.get(1))
+        .ok_or_else(|| ParseError::at(input, line, "Expected \"Card <id>:\""))?;
+    parse_at(input, id.as_str())
+}
+
+fn parse_numbers(input: &str, numbers: &str) -> Result<HashSet<u8>, ParseError> {
+    numbers.split(' ').filter(|x| !x.is_empty()).map(|x| parse_at(input, x)).collect()
+}
+
+fn get_num_matching(input: &str, line: &str) -> Result<(usize, usize), ParseError> {
+    let card_id = get_card_id(input, line)?;
+    let (_, numbers) = split_once_at(input, line, ": ")?;
+    let (picked, winning) = split_once_at(input, numbers, " | ")?;
+    let picked_numbers = parse_numbers(input, picked)?;
+    let winning_numbers = parse_numbers(input, winning)?;
+
+    let matching_numbers: usize = picked_numbers.intersection(&winning_numbers).count();
+
+    Ok((card_id, matching_numbers))
+}
+
+fn calc_full_score(num_matches_per_game: Vec<usize>) -> usize {
+    debug!("Length of game results is {}", num_matches_per_game.len());
+    let mut num_copies: Vec<usize> = vec![1; num_matches_per_game.len()];
+    for game_id in 0..num_matches_per_game.len() - 1 {
+        let current_matches = &num_matches_per_game[game_id];
+        trace!("Game {} has {} copies", game_id, num_copies[game_id]);
+        for _ in 0..num_copies[game_id] {
+            for i in game_id..(game_id + current_matches) {
+                let increasing_id = i + 1;
+                if increasing_id < num_matches_per_game.len() {
+                    num_copies[i + 1] += 1;
+                }
+            }
+        }
+    }
+    let total_num_games: usize = num_copies.iter().sum();
+    total_num_games
+}
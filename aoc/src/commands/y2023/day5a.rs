@@ -0,0 +1,139 @@
+use clap::Parser;
+use log::{debug, trace};
+use std::collections::HashMap;
+
+use super::{
+    mark_parsed, parse_at, records, split_once_at, Answer, CommandImpl, DynError, ParseError,
+};
+
+#[derive(Parser, Debug)]
+pub struct Day5a;
+
+impl CommandImpl for Day5a {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let mut split_input = records(input).into_iter();
+        let seed_line = split_input.next().ok_or_else(|| ParseError::new("The input is empty"))?;
+        let (_, seeds) = split_once_at(input, seed_line, ":")?;
+        let seeds: Vec<usize> = seeds
+            .split_ascii_whitespace()
+            .map(|x| parse_at(input, x))
+            .collect::<Result<Vec<usize>, _>>()?;
+
+        let mut conversion_maps: HashMap<String, Vec<ConversionMap>> = HashMap::new();
+
+        for line in split_input {
+            let (source_category, ranges) = generate_maps(input, line)?;
+            trace!("Conversion maps for {source_category}: {ranges:?}");
+            conversion_maps.insert(source_category, ranges);
+        }
+        mark_parsed();
+        let mut lowest_location = 99999999999;
+        for seed_number in seeds {
+            let mut source_category: String = "seed".to_owned();
+            let mut source_number = seed_number;
+            let final_destination = "location";
+            while source_category != final_destination {
+                let (destination_target, destination_number) = convert_source_to_dest(
+                    &source_category,
+                    source_number,
+                    conversion_maps
+                        .get(&source_category)
+                        .ok_or_else(|| ParseError::new(format!("No map from {source_category}")))?,
+                );
+                source_number = destination_number;
+                source_category = destination_target;
+            }
+            if source_number < lowest_location {
+                debug!("Seed {seed_number} has currently the lowest location {source_number}");
+                lowest_location = source_number;
+            }
+        }
+
+        Ok(lowest_location.into())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ConversionMap {
+    source_category: String,
+    destination_category: String,
+    source_range_start: usize,
+    destination_range_start: usize,
+    range_length: usize,
+}
+
+impl ConversionMap {
+    fn convert_number(&self, source_number: usize) -> Option<usize> {
+        if (source_number >= self.source_range_start)
+            & (source_number < self.source_range_start + self.range_length)
+        {
+            return Some(self.destination_range_start + (source_number - self.source_range_start));
+        }
+        None
+    }
+    fn check_source_category(&self, comparison_category: &str) -> bool {
+        self.source_category == comparison_category
+    }
+
+    fn get_destination(&self) -> &str {
+        &self.destination_category
+    }
+}
+
+// takes in multiple lines starting with the description and generates conversion maps
+fn generate_maps(input: &str, multiline: &str) -> Result<(String, Vec<ConversionMap>), ParseError> {
+    let mut split_lines = multiline.lines();
+    let first_line = split_lines.next().expect("Records are not empty");
+    // e.g. `seed-to-soil map:`
+    let name = first_line.split_ascii_whitespace().next().unwrap_or(first_line);
+    let (source_category, destination_category) = split_once_at(input, name, "-to-")?;
+    let mut ranges: Vec<ConversionMap> = Vec::new();
+    for line in split_lines {
+        if !line.is_empty() {
+            ranges.push(generate_map(input, source_category, destination_category, line)?)
+        }
+    }
+    Ok((source_category.to_owned(), ranges))
+}
+
+fn generate_map(
+    input: &str,
+    source_category: &str,
+    destination_category: &str,
+    line: &str,
+) -> Result<ConversionMap, ParseError> {
+    let values = line
+        .split_ascii_whitespace()
+        .map(|x| parse_at(input, x))
+        .collect::<Result<Vec<usize>, _>>()?;
+    let [destination_range_start, source_range_start, range_length] = values[..] else {
+        return Err(ParseError::at(
+            input,
+            line,
+            "Expected destination start, source start and length",
+        ));
+    };
+
+    Ok(ConversionMap {
+        source_category: source_category.to_owned(),
+        destination_category: destination_category.to_owned(),
+        destination_range_start,
+        source_range_start,
+        range_length,
+    })
+}
+
+fn convert_source_to_dest(
+    source_category: &str,
+    number: usize,
+    ranges: &Vec<ConversionMap>,
+) -> (String, usize) {
+    let mut destination_target: String = "".to_owned();
+    for conversion in ranges {
+        destination_target = conversion.get_destination().to_owned();
+        if let Some(converted_number) = conversion.convert_number(number) {
+            return (destination_target, converted_number);
+        }
+    }
+    (destination_target, number)
+}
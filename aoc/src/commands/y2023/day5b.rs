@@ -0,0 +1,114 @@
+use super::{
+    mark_parsed, parse_at, records, split_once_at, Answer, CommandImpl, DynError, ParseError,
+};
+use crate::utils::interval::{Interval, RangeMap, RangeSet};
+use clap::Parser;
+use log::{debug, trace};
+use std::collections::HashMap;
+
+#[derive(Parser, Debug)]
+pub struct Day5b;
+
+impl CommandImpl for Day5b {
+    /// Maps the seed ranges as a whole through the composed conversion tables
+    const VARIANT: Option<&'static str> = Some("ranges");
+
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let mut split_input = records(input).into_iter();
+        let seed_line = split_input.next().ok_or_else(|| ParseError::new("The input is empty"))?;
+        let (_, seeds) = split_once_at(input, seed_line, ":")?;
+        let seed_line = seeds
+            .split_ascii_whitespace()
+            .map(|x| parse_at(input, x))
+            .collect::<Result<Vec<i64>, _>>()?;
+        if seed_line.len() % 2 != 0 {
+            return Err(
+                ParseError::at(input, seeds, "Expected pairs of seed start and range").into()
+            );
+        }
+        let seeds: RangeSet = seed_line
+            .chunks(2)
+            .map(|pair| {
+                Interval::with_len(pair[0], pair[1]).ok_or_else(|| {
+                    let message = format!("The seeds {} {} go past {}", pair[0], pair[1], i64::MAX);
+                    ParseError::at(input, seeds.trim(), message)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let mut conversion_maps: HashMap<&str, (&str, RangeMap)> = HashMap::new();
+        for line in split_input {
+            let (source_category, destination_category, map) = generate_map(input, line)?;
+            trace!("Conversion map from {source_category} to {destination_category}: {map:?}");
+            conversion_maps.insert(source_category, (destination_category, map));
+        }
+        mark_parsed();
+
+        // one map from seed to location, following the categories
+        let mut seed_to_location = RangeMap::new();
+        let mut category = "seed";
+        for _ in 0..conversion_maps.len() {
+            if category == "location" {
+                break;
+            }
+            let (destination, map) = conversion_maps
+                .get(category)
+                .ok_or_else(|| ParseError::new(format!("No map from {category}")))?;
+            seed_to_location = seed_to_location.then(map);
+            category = destination;
+        }
+        if category != "location" {
+            return Err(ParseError::new("The maps never lead from seed to location").into());
+        }
+
+        let locations = seed_to_location.map_set(&seeds);
+        debug!("{} seeds end up in {} location ranges", seeds.len(), locations.intervals().len());
+        let lowest_location =
+            locations.min().ok_or_else(|| ParseError::new("There are no seeds"))?;
+        Ok(lowest_location.into())
+    }
+}
+
+// takes in multiple lines starting with the description and generates the conversion map
+fn generate_map<'a>(
+    input: &str,
+    multiline: &'a str,
+) -> Result<(&'a str, &'a str, RangeMap), ParseError> {
+    let mut split_lines = multiline.lines();
+    let first_line = split_lines.next().expect("Records are not empty");
+    // e.g. `seed-to-soil map:`
+    let name = first_line.split_ascii_whitespace().next().unwrap_or(first_line);
+    let (source_category, destination_category) = split_once_at(input, name, "-to-")?;
+    let mut map = RangeMap::new();
+    for line in split_lines.filter(|line| !line.is_empty()) {
+        let values = line
+            .split_ascii_whitespace()
+            .map(|x| parse_at(input, x))
+            .collect::<Result<Vec<i64>, _>>()?;
+        let [destination_range_start, source_range_start, range_length] = values[..] else {
+            return Err(ParseError::at(
+                input,
+                line,
+                "Expected destination start, source start and length",
+            ));
+        };
+        let source = Interval::with_len(source_range_start, range_length).ok_or_else(|| {
+            ParseError::at(input, line, format!("The range goes past {}", i64::MAX))
+        })?;
+        map.insert(source, destination_range_start - source_range_start);
+    }
+    Ok((source_category, destination_category, map))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_overflow() {
+        let error = Day5b.main("seeds: 9223372036854775807 5\n").unwrap_err();
+        assert!(error.to_string().contains("The seeds 9223372036854775807 5 go past"));
+        let input = "seeds: 1 2\n\nseed-to-location map:\n0 9223372036854775800 10\n";
+        assert!(Day5b.main(input).unwrap_err().to_string().contains("The range goes past"));
+    }
+}
@@ -0,0 +1,191 @@
+use super::{
+    mark_parsed, parse_at, records, split_once_at, Answer, CommandImpl, DynError, ParseError,
+};
+use clap::Parser;
+use log::{debug, info, trace};
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+#[derive(Parser, Debug)]
+pub struct Day5b2;
+
+impl CommandImpl for Day5b2 {
+    /// Converts every seed with precomputed offsets per range
+    const VARIANT: Option<&'static str> = Some("offsets");
+    /// Still converts every single seed, which takes minutes on the challenge input
+    const SLOW: bool = true;
+
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let mut split_input = records(input).into_iter();
+        let seeds = split_input.next().ok_or_else(|| ParseError::new("The input is empty"))?;
+        let (_, seeds) = split_once_at(input, seeds, ":")?;
+        let mut seed_line: VecDeque<usize> = seeds
+            .split_ascii_whitespace()
+            .map(|x| parse_at(input, x))
+            .collect::<Result<VecDeque<usize>, _>>()?;
+        if seed_line.len() % 2 != 0 {
+            return Err(
+                ParseError::at(input, seeds, "Expected pairs of seed start and range").into()
+            );
+        }
+
+        let mut offset_maps: HashMap<ConvType, (ConvType, Vec<Offset>)> = HashMap::new();
+
+        for line in split_input {
+            let (source_category, destination_category, offsets) =
+                generate_boundary_and_offset_arrays(input, line)?;
+            offset_maps.insert(source_category, (destination_category, offsets));
+        }
+        mark_parsed();
+        let mut lowest_location = 99999999999;
+
+        let final_destination = ConvType::Location;
+
+        for i in 0..seed_line.len() / 2 {
+            let seed_start: usize = seed_line.pop_front().expect("Couldnt get seed start");
+            let seed_range: usize = seed_line.pop_front().expect("Couldnt get seed range");
+            info!("Calculating range{i} from {seed_start} to {}", seed_start + seed_range - 1);
+
+            for seed_number in seed_start..seed_start + seed_range {
+                let mut source_category = ConvType::Seed;
+                let mut source_number = seed_number;
+
+                while source_category != final_destination {
+                    let (destination_target, destination_offsets) =
+                        offset_maps.get(&source_category).ok_or_else(|| {
+                            let source = source_category.to_string();
+                            ParseError::new(format!("No map from {source}"))
+                        })?;
+                    let destination_number = convert_number(source_number, destination_offsets);
+                    source_number = destination_number;
+                    source_category = destination_target.clone();
+                }
+                if source_number < lowest_location {
+                    debug!("Seed {seed_number} has currently the lowest location {source_number}");
+                    lowest_location = source_number;
+                }
+            }
+        }
+
+        Ok(lowest_location.into())
+    }
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+enum ConvType {
+    Seed,
+    Soil,
+    Fertilizer,
+    Water,
+    Light,
+    Temperature,
+    Humidity,
+    Location,
+}
+
+impl ConvType {
+    fn from_str(s: &str) -> Option<ConvType> {
+        match s {
+            "seed" => Some(ConvType::Seed),
+            "soil" => Some(ConvType::Soil),
+            "fertilizer" => Some(ConvType::Fertilizer),
+            "water" => Some(ConvType::Water),
+            "light" => Some(ConvType::Light),
+            "temperature" => Some(ConvType::Temperature),
+            "humidity" => Some(ConvType::Humidity),
+            "location" => Some(ConvType::Location),
+            _ => None,
+        }
+    }
+    fn to_string(&self) -> &'static str {
+        match self {
+            ConvType::Seed => "Seed",
+            ConvType::Soil => "Soil",
+            ConvType::Fertilizer => "Fertilizer",
+            ConvType::Water => "Water",
+            ConvType::Light => "Light",
+            ConvType::Temperature => "Temperature",
+            ConvType::Humidity => "Humidity",
+            ConvType::Location => "Location",
+        }
+    }
+}
+
+struct Offset {
+    start: usize,
+    end: usize,
+    offset: i64,
+}
+
+fn convert_number(number: usize, offset_data: &Vec<Offset>) -> usize {
+    for offset in offset_data {
+        if offset.start <= number && number <= offset.end {
+            let offset_number = (number as i64)
+                .checked_add(offset.offset)
+                .expect("Overflow when adding offset to number!");
+            return offset_number as usize;
+        }
+    }
+    number
+}
+
+// takes in multiple lines starting with the description and generates conversion maps
+fn generate_boundary_and_offset_arrays(
+    input: &str,
+    multiline: &str,
+) -> Result<(ConvType, ConvType, Vec<Offset>), ParseError> {
+    let split_lines: Vec<&str> = multiline.lines().collect();
+    let first_line = split_lines[0];
+    // e.g. `seed-to-soil map:`
+    let name = first_line.split_ascii_whitespace().next().unwrap_or(first_line);
+    let (source, destination) = split_once_at(input, name, "-to-")?;
+    let category = |name| {
+        ConvType::from_str(name)
+            .ok_or_else(|| ParseError::at(input, name, format!("Unknown category {name}")))
+    };
+    let (source_category, destination_category) = (category(source)?, category(destination)?);
+    trace!(
+        "Source: {}, Destination: {}",
+        source_category.to_string(),
+        destination_category.to_string()
+    );
+    //start range, end_range, offset
+    let mut offset_data: Vec<Offset> = Vec::with_capacity(split_lines.len());
+
+    for &line in &split_lines[1..] {
+        if !line.is_empty() {
+            let (source_start, source_end, offset) = extract_boundaries_and_offset(input, line)?;
+            offset_data.push(Offset { start: source_start, end: source_end, offset });
+        }
+    }
+    Ok((source_category, destination_category, offset_data))
+}
+
+// source start, source end (inclusive), offset
+fn extract_boundaries_and_offset(
+    input: &str,
+    line: &str,
+) -> Result<(usize, usize, i64), ParseError> {
+    let values = line
+        .split_ascii_whitespace()
+        .map(|x| parse_at(input, x))
+        .collect::<Result<Vec<usize>, _>>()?;
+    let [destination_start, source_start, range] = values[..] else {
+        return Err(ParseError::at(
+            input,
+            line,
+            "Expected destination start, source start and length",
+        ));
+    };
+    let range_end = (source_start + range)
+        .checked_sub(1)
+        .ok_or_else(|| ParseError::at(input, line, "Empty range"))?;
+    let offset: i64 = (destination_start as i64)
+        .checked_sub(source_start as i64)
+        .ok_or_else(|| ParseError::at(input, line, "Offset does not fit in an i64"))?;
+    trace!(
+        "Destination start: {destination_start}, Source start: {source_start}, range: {range}, \
+        calculated range end: {range_end}, calculated offset: {offset}"
+    );
+    Ok((source_start, range_end, offset))
+}
@@ -0,0 +1,86 @@
+use super::{mark_parsed, parse_at, split_once_at, Answer, CommandImpl, DynError, ParseError};
+use clap::Parser;
+use log::debug;
+
+#[derive(Parser, Debug)]
+pub struct Day6a;
+
+impl CommandImpl for Day6a {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let races = parse_input(input)?;
+        mark_parsed();
+        for race in &races {
+            let (lower_limit, upper_limit) = race.find_charging_limits()?;
+            debug!(
+                "t_total: {}, distance: {}, lower limit: {lower_limit}, upper limit: {upper_limit}, \
+                possible wins: {}",
+                race.t_total,
+                race.min_distance,
+                race.num_possible_wins()
+            );
+        }
+        let num_wins: Vec<usize> = races.iter().map(|x| x.num_possible_wins().to_owned()).collect();
+        let result: usize = num_wins.into_iter().product();
+        Ok(result.into())
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Boatrace {
+    t_total: usize,
+    min_distance: usize,
+}
+
+impl Boatrace {
+    fn find_charging_limits(&self) -> Result<(f64, f64), String> {
+        let t_total = self.t_total as f64;
+        let distance = self.min_distance as f64;
+        let lower_limit = 0.5_f64 * t_total - (0.25_f64 * t_total.powi(2) - distance).sqrt();
+        let upper_limit = 0.5_f64 * t_total + (0.25_f64 * t_total.powi(2) - distance).sqrt();
+        Ok((lower_limit, upper_limit))
+    }
+    fn num_possible_wins(&self) -> usize {
+        match self.find_charging_limits() {
+            Ok((lower_bound, upper_bound)) => {
+                if lower_bound.fract() != 0.0 {
+                    upper_bound.ceil() as usize - lower_bound.ceil() as usize
+                } else if upper_bound.ceil() as usize == lower_bound.ceil() as usize {
+                    0
+                } else {
+                    upper_bound.ceil() as usize - lower_bound.ceil() as usize - 1
+                }
+            }
+            Err(_) => 0_usize,
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Boatrace>, ParseError> {
+    let mut split_lines = input.lines();
+    let (Some(time_line), Some(distance_line)) = (split_lines.next(), split_lines.next()) else {
+        return Err(ParseError::new("Expected a line of times and a line of distances"));
+    };
+    let times = parse_numbers(input, time_line)?;
+    let distances = parse_numbers(input, distance_line)?;
+    if times.len() != distances.len() {
+        return Err(ParseError::at(input, distance_line, "Expected as many distances as times"));
+    }
+    let mut races: Vec<Boatrace> = Vec::new();
+    for (time, distance) in times.iter().zip(distances.iter()) {
+        races.push(Boatrace { t_total: time.to_owned(), min_distance: distance.to_owned() })
+    }
+    Ok(races)
+}
+
+// the numbers after the label, e.g. `Time:      7  15   30`
+fn parse_numbers(input: &str, line: &str) -> Result<Vec<usize>, ParseError> {
+    let (_, numbers) = split_once_at(input, line, ":")?;
+    numbers.split_ascii_whitespace().map(|x| parse_at(input, x)).collect()
+}
+
+/*
+fn product_of_ints_in_range(lower_bound: f64, upper_bound: f64) -> usize {
+    let range: std::ops::Range<usize> = lower_bound.ceil() as usize..upper_bound.ceil() as usize;
+    range.product()
+}
+*/
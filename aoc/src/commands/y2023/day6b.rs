@@ -1,20 +1,15 @@
-use super::{CommandImpl, DynError};
+use super::{mark_parsed, split_once_at, Answer, CommandImpl, DynError, ParseError};
 use clap::Parser;
-use std::fs;
-use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
-pub struct Day6b {
-    #[clap(long, short)]
-    input: PathBuf,
-}
+pub struct Day6b;
 
 impl CommandImpl for Day6b {
-    fn main(&self) -> Result<(), DynError> {
-        let race = parse_input(&self.input);
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let race = parse_input(input)?;
+        mark_parsed();
         let result: usize = race.num_possible_wins();
-        println!("Day6a: {result}");
-        Ok(())
+        Ok(result.into())
     }
 }
 
@@ -28,7 +23,7 @@ impl Boatrace {
     fn find_charging_limits(&self) -> Result<(f64, f64), String> {
         let t_total = self.t_total as f64;
         let distance = self.min_distance as f64;
-        let square_root = (0.25_f64 * t_total.powi(2) as f64 - distance).sqrt();
+        let square_root = (0.25_f64 * t_total.powi(2) - distance).sqrt();
         let lower_limit = 0.5_f64 * t_total - square_root;
         let upper_limit = 0.5_f64 * t_total + square_root;
         Ok((lower_limit, upper_limit))
@@ -49,33 +44,25 @@ impl Boatrace {
     }
 }
 
-fn parse_input(path: &PathBuf) -> Boatrace {
-    let lines = fs::read_to_string(path).unwrap();
-    let mut split_lines = lines.lines();
-    let time: usize = split_lines
-        .next()
-        .unwrap()
-        .split(":")
-        .nth(1)
-        .unwrap()
-        .chars()
-        .filter(|c| !c.is_whitespace())
-        .collect::<String>()
-        .parse()
-        .expect("Could not convert times to usize");
+fn parse_input(input: &str) -> Result<Boatrace, ParseError> {
+    let mut split_lines = input.lines();
+    let (Some(time_line), Some(distance_line)) = (split_lines.next(), split_lines.next()) else {
+        return Err(ParseError::new("Expected a line of times and a line of distances"));
+    };
+    let time = parse_kerned_number(input, time_line)?;
+    let distance = parse_kerned_number(input, distance_line)?;
+    Ok(Boatrace { t_total: time, min_distance: distance })
+}
 
-    let distance: usize = split_lines
-        .next()
-        .unwrap()
-        .split(":")
-        .nth(1)
-        .unwrap()
+// the digits after the label read as one number, e.g. `Time:      7  15   30` is 71530
+fn parse_kerned_number(input: &str, line: &str) -> Result<usize, ParseError> {
+    let (_, digits) = split_once_at(input, line, ":")?;
+    digits
         .chars()
         .filter(|c| !c.is_whitespace())
         .collect::<String>()
         .parse()
-        .expect("Could not convert distances to usize");
-    Boatrace { t_total: time.to_owned(), min_distance: distance.to_owned() }
+        .map_err(|e| ParseError::at(input, digits, format!("Could not parse {digits:?}: {e}")))
 }
 
 /*
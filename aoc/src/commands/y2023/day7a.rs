@@ -1,31 +1,26 @@
-use super::{CommandImpl, DynError};
+use super::{mark_parsed, parse_at, Answer, CommandImpl, DynError, ParseError};
 use clap::Parser;
+use log::trace;
 use std::collections::HashMap;
-use std::fs;
-use std::path::PathBuf;
 use strum::{EnumCount, IntoEnumIterator};
 use strum_macros::{EnumCount as EnumCountMacro, EnumIter};
 
 #[derive(Parser, Debug)]
-pub struct Day7a {
-    #[clap(long, short)]
-    input: PathBuf,
-}
+pub struct Day7a;
 
 impl CommandImpl for Day7a {
-    fn main(&self) -> Result<(), DynError> {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
         let mut result = 0;
         let mut hands = Vec::new();
-        for line in fs::read_to_string(&self.input).unwrap().lines() {
-            hands.push(Hand::from_string(line));
+        for line in input.lines() {
+            hands.push(Hand::from_string(input, line)?);
         }
+        mark_parsed();
         hands.sort_by_key(|hand| hand.power);
         for (i, hand) in hands.iter().enumerate() {
-            //dbg!(&hands);
             result += (i + 1) * hand.bid;
         }
-        println!("Day7a: {result}");
-        Ok(())
+        Ok(result.into())
     }
 }
 
@@ -62,7 +57,7 @@ impl CardValue {
             '4' => Ok(CardValue::Four),
             '3' => Ok(CardValue::Three),
             '2' => Ok(CardValue::Two),
-            _ => Err("No matching card value found for char {c}".to_string()),
+            _ => Err(format!("No matching card value found for char {c}")),
         }
     }
 }
@@ -87,19 +82,31 @@ struct Hand {
 }
 
 impl Hand {
-    pub fn from_string(line: &str) -> Self {
+    /// Parse a line such as `32T3K 765`, which has to be a slice of `input`
+    pub fn from_string(input: &str, line: &str) -> Result<Self, ParseError> {
         let mut split = line.split_ascii_whitespace();
-        let cards: Vec<CardValue> =
-            split.next().unwrap().chars().map(|x| CardValue::from_char(x).unwrap()).collect();
-        let bid: usize = split.next().unwrap().parse().unwrap();
-        Hand::new(cards, bid).unwrap()
+        let (Some(card_chars), Some(bid)) = (split.next(), split.next()) else {
+            return Err(ParseError::at(input, line, "Expected cards and a bid"));
+        };
+        let cards = card_chars
+            .char_indices()
+            .map(|(i, c)| {
+                let card = &card_chars[i..i + c.len_utf8()];
+                CardValue::from_char(c).map_err(|e| ParseError::at(input, card, e))
+            })
+            .collect::<Result<Vec<CardValue>, _>>()?;
+        if cards.len() != 5 {
+            return Err(ParseError::at(input, card_chars, "Expected 5 cards"));
+        }
+        let bid: usize = parse_at(input, bid)?;
+        Hand::new(cards, bid).map_err(|e| ParseError::at(input, line, e))
     }
 
     pub fn new(cards: Vec<CardValue>, bid: usize) -> Result<Self, String> {
-        let mut new_hand = Self { cards: cards, bid: bid, handtype: None, power: 0 };
+        let mut new_hand = Self { cards, bid, handtype: None, power: 0 };
         match new_hand.assign_handtype() {
             Ok(()) => match new_hand.calculate_power() {
-                Ok(()) => return Ok(new_hand),
+                Ok(()) => Ok(new_hand),
                 _ => Err("Could not calculate power".to_string()),
             },
             _ => Err("Could not calculate handtype".to_string()),
@@ -107,33 +114,26 @@ impl Hand {
     }
     fn get_handtype(&self) -> Result<HandType, String> {
         let mut occurences_per_value: Vec<usize> = vec![0; CardValue::COUNT];
-        //dbg!(&self.cards);
         for card in &self.cards {
             occurences_per_value[*card as usize] += 1;
         }
-        //dbg!(&occurences_per_value);
         occurences_per_value.sort();
 
-        //dbg!(&occurences_per_value);
         match occurences_per_value.pop() {
-            Some(1) => return Ok(HandType::HighCard),
+            Some(1) => Ok(HandType::HighCard),
             Some(2) => match occurences_per_value.pop() {
-                Some(2) => return Ok(HandType::TwoPair),
-                Some(1) => return Ok(HandType::OnePair),
-                _ | None => {
-                    Err("Hand has a match of 2, but could not determine second match".to_string())
-                }
+                Some(2) => Ok(HandType::TwoPair),
+                Some(1) => Ok(HandType::OnePair),
+                _ => Err("Hand has a match of 2, but could not determine second match".to_string()),
             },
             Some(3) => match occurences_per_value.pop() {
-                Some(2) => return Ok(HandType::FullHouse),
-                Some(1) => return Ok(HandType::ThreeKind),
-                _ | None => {
-                    Err("Hand has a match of 3, but could not determine second match".to_string())
-                }
+                Some(2) => Ok(HandType::FullHouse),
+                Some(1) => Ok(HandType::ThreeKind),
+                _ => Err("Hand has a match of 3, but could not determine second match".to_string()),
             },
-            Some(4) => return Ok(HandType::FourKind),
-            Some(5) => return Ok(HandType::FiveKind),
-            _ | None => Err("Card counting didn't work".to_string()),
+            Some(4) => Ok(HandType::FourKind),
+            Some(5) => Ok(HandType::FiveKind),
+            _ => Err("Card counting didn't work".to_string()),
         }
     }
     fn assign_handtype(&mut self) -> Result<(), String> {
@@ -144,14 +144,12 @@ impl Hand {
     fn calculate_power(&mut self) -> Result<(), String> {
         let mut tmp_str: String =
             (self.handtype.expect("Could not read HandType") as usize).to_string();
-        //dbg!(&tmp_str);
         for card in &self.cards {
-            //dbg!(card);
             tmp_str.push_str(&format!("{:02}", (*card as usize)));
         }
-        //dbg!(&tmp_str);
         assert_eq!(tmp_str.len(), 11);
         self.power = tmp_str.parse().expect("Could not convert power to usize");
+        trace!("{:?} is a {:?} with power {}", self.cards, self.handtype, self.power);
         Ok(())
     }
 }
@@ -161,23 +159,24 @@ impl Hand {
 mod tests {
 
     use super::*;
+    use std::fs;
     #[test]
     fn card_comparisons() {
         let result = (CardValue::Ace as i32) > (CardValue::Nine as i32);
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
     fn hands_comparisons() {
         let result = (HandType::FourKind as i32) > (HandType::HighCard as i32);
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
     fn test_handtype() {
         let cards =
             vec![CardValue::Ace, CardValue::Ace, CardValue::Eight, CardValue::Ace, CardValue::Ace];
-        let hand = Hand { cards: cards, bid: 0, handtype: None, power: 0 };
+        let hand = Hand { cards, bid: 0, handtype: None, power: 0 };
         let handtype = hand.get_handtype().expect("Could not determine hand type");
 
         assert_eq!(handtype, HandType::FourKind);
@@ -189,7 +188,7 @@ mod tests {
             CardValue::Ace,
             CardValue::Ace,
         ];
-        let hand = Hand { cards: cards, bid: 0, handtype: None, power: 0 };
+        let hand = Hand { cards, bid: 0, handtype: None, power: 0 };
         let handtype = hand.get_handtype().expect("Could not determine hand type");
 
         assert_eq!(handtype, HandType::ThreeKind);
@@ -201,7 +200,7 @@ mod tests {
             CardValue::Eight,
             CardValue::Ace,
         ];
-        let hand = Hand { cards: cards, bid: 0, handtype: None, power: 0 };
+        let hand = Hand { cards, bid: 0, handtype: None, power: 0 };
         let handtype = hand.get_handtype().expect("Could not determine hand type");
 
         assert_eq!(handtype, HandType::FullHouse);
@@ -213,7 +212,7 @@ mod tests {
             CardValue::Eight,
             CardValue::Ace,
         ];
-        let hand = Hand { cards: cards, bid: 0, handtype: None, power: 0 };
+        let hand = Hand { cards, bid: 0, handtype: None, power: 0 };
         let handtype = hand.get_handtype().expect("Could not determine hand type");
 
         assert_eq!(handtype, HandType::TwoPair);
@@ -224,15 +223,13 @@ mod tests {
         let cards =
             vec![CardValue::Ace, CardValue::Ace, CardValue::Eight, CardValue::Ace, CardValue::Ace];
         let hand = Hand::new(cards, 0).expect("Could not generate new hand");
-        //let assignment = hand.assign_handtype().unwrap();
-        //assert_eq!(assignment, ());
         assert_eq!(hand.handtype, Some(HandType::FourKind))
     }
 
     #[test]
     fn test_from_string() {
         let input = "32T3K 765".to_string();
-        let hand = Hand::from_string(&input);
+        let hand = Hand::from_string(&input, &input).unwrap();
         let cards = vec![
             CardValue::Three,
             CardValue::Two,
@@ -244,5 +241,17 @@ mod tests {
         assert_eq!(hand.cards, cards);
         assert_eq!(hand.bid, bid);
         assert_eq!(hand.handtype, Some(HandType::OnePair));
+
+        let input = "32T3K 765\nKK6X7 28";
+        let error = Hand::from_string(input, &input[10..]).unwrap_err();
+        assert_eq!(error.position(), Some((2, 4)));
+        let error = Hand::from_string(input, &input[..5]).unwrap_err();
+        assert_eq!(error.msg(), "Expected cards and a bid");
+    }
+
+    #[test]
+    fn evaluate_test() {
+        let input = fs::read_to_string("inputs/2023/day_7/test1.txt").unwrap();
+        assert_eq!(Day7a.main(&input).unwrap(), Answer::Int(6440));
     }
 }
@@ -1,32 +1,28 @@
 use clap::Parser;
+use log::trace;
 use std::collections::HashMap;
-use std::path::PathBuf;
-use std::{collections::VecDeque, fs};
+use std::collections::VecDeque;
 use strum::{EnumCount, IntoEnumIterator};
 use strum_macros::{EnumCount as EnumCountMacro, EnumIter};
 
-use super::{CommandImpl, DynError};
+use super::{mark_parsed, parse_at, Answer, CommandImpl, DynError, ParseError};
 
 #[derive(Parser, Debug)]
-pub struct Day7b {
-    #[clap(long, short)]
-    input: PathBuf,
-}
+pub struct Day7b;
 
 impl CommandImpl for Day7b {
-    fn main(&self) -> Result<(), DynError> {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
         let mut result = 0;
         let mut hands = Vec::new();
-        for line in fs::read_to_string(&self.input).unwrap().lines() {
-            hands.push(Hand::from_string(line));
+        for line in input.lines() {
+            hands.push(Hand::from_string(input, line)?);
         }
+        mark_parsed();
         hands.sort_by_key(|hand| hand.power);
         for (i, hand) in hands.iter().enumerate() {
-            //dbg!(&hands);
             result += (i + 1) * hand.bid;
         }
-        println!("Day7b: {result}");
-        Ok(())
+        Ok(result.into())
     }
 }
 
@@ -63,7 +59,7 @@ impl CardValue {
             '4' => Ok(CardValue::Four),
             '3' => Ok(CardValue::Three),
             '2' => Ok(CardValue::Two),
-            _ => Err("No matching card value found for char {c}".to_string()),
+            _ => Err(format!("No matching card value found for char {c}")),
         }
     }
 }
@@ -88,19 +84,31 @@ struct Hand {
 }
 
 impl Hand {
-    pub fn from_string(line: &str) -> Self {
+    /// Parse a line such as `32T3K 765`, which has to be a slice of `input`
+    pub fn from_string(input: &str, line: &str) -> Result<Self, ParseError> {
         let mut split = line.split_ascii_whitespace();
-        let cards: Vec<CardValue> =
-            split.next().unwrap().chars().map(|x| CardValue::from_char(x).unwrap()).collect();
-        let bid: usize = split.next().unwrap().parse().unwrap();
-        Hand::new(cards, bid).unwrap()
+        let (Some(card_chars), Some(bid)) = (split.next(), split.next()) else {
+            return Err(ParseError::at(input, line, "Expected cards and a bid"));
+        };
+        let cards = card_chars
+            .char_indices()
+            .map(|(i, c)| {
+                let card = &card_chars[i..i + c.len_utf8()];
+                CardValue::from_char(c).map_err(|e| ParseError::at(input, card, e))
+            })
+            .collect::<Result<Vec<CardValue>, _>>()?;
+        if cards.len() != 5 {
+            return Err(ParseError::at(input, card_chars, "Expected 5 cards"));
+        }
+        let bid: usize = parse_at(input, bid)?;
+        Hand::new(cards, bid).map_err(|e| ParseError::at(input, line, e))
     }
 
     pub fn new(cards: Vec<CardValue>, bid: usize) -> Result<Self, String> {
-        let mut new_hand = Self { cards: cards, bid: bid, handtype: None, power: 0 };
+        let mut new_hand = Self { cards, bid, handtype: None, power: 0 };
         match new_hand.assign_handtype() {
             Ok(()) => match new_hand.calculate_power() {
-                Ok(()) => return Ok(new_hand),
+                Ok(()) => Ok(new_hand),
                 _ => Err("Could not calculate power".to_string()),
             },
             _ => Err("Could not calculate handtype".to_string()),
@@ -108,36 +116,29 @@ impl Hand {
     }
     fn get_handtype(&self) -> Result<HandType, String> {
         let mut occurences_per_value: Vec<usize> = vec![0; CardValue::COUNT];
-        //dbg!(&self.cards);
         for card in &self.cards {
             occurences_per_value[*card as usize] += 1;
         }
-        //dbg!(&occurences_per_value);
-        let num_jacks = occurences_per_value[0].clone();
+        let num_jacks = occurences_per_value[0];
         occurences_per_value.remove(0);
 
         occurences_per_value.sort();
         occurences_per_value[CardValue::COUNT - 2] += num_jacks;
-        //dbg!(&occurences_per_value);
         match occurences_per_value.pop() {
-            Some(1) => return Ok(HandType::HighCard),
+            Some(1) => Ok(HandType::HighCard),
             Some(2) => match occurences_per_value.pop() {
-                Some(2) => return Ok(HandType::TwoPair),
-                Some(1) => return Ok(HandType::OnePair),
-                _ | None => {
-                    Err("Hand has a match of 2, but could not determine second match".to_string())
-                }
+                Some(2) => Ok(HandType::TwoPair),
+                Some(1) => Ok(HandType::OnePair),
+                _ => Err("Hand has a match of 2, but could not determine second match".to_string()),
             },
             Some(3) => match occurences_per_value.pop() {
-                Some(2) => return Ok(HandType::FullHouse),
-                Some(1) => return Ok(HandType::ThreeKind),
-                _ | None => {
-                    Err("Hand has a match of 3, but could not determine second match".to_string())
-                }
+                Some(2) => Ok(HandType::FullHouse),
+                Some(1) => Ok(HandType::ThreeKind),
+                _ => Err("Hand has a match of 3, but could not determine second match".to_string()),
             },
-            Some(4) => return Ok(HandType::FourKind),
-            Some(5) => return Ok(HandType::FiveKind),
-            _ | None => Err("Card counting didn't work".to_string()),
+            Some(4) => Ok(HandType::FourKind),
+            Some(5) => Ok(HandType::FiveKind),
+            _ => Err("Card counting didn't work".to_string()),
         }
     }
     fn assign_handtype(&mut self) -> Result<(), String> {
@@ -148,14 +149,12 @@ impl Hand {
     fn calculate_power(&mut self) -> Result<(), String> {
         let mut tmp_str: String =
             (self.handtype.expect("Could not read HandType") as usize).to_string();
-        //dbg!(&tmp_str);
         for card in &self.cards {
-            //dbg!(card);
             tmp_str.push_str(&format!("{:02}", (*card as usize)));
         }
-        //dbg!(&tmp_str);
         assert_eq!(tmp_str.len(), 11);
         self.power = tmp_str.parse().expect("Could not convert power to usize");
+        trace!("{:?} is a {:?} with power {}", self.cards, self.handtype, self.power);
         Ok(())
     }
 }
@@ -165,23 +164,24 @@ impl Hand {
 mod tests {
 
     use super::*;
+    use std::fs;
     #[test]
     fn card_comparisons() {
         let result = (CardValue::Ace as i32) > (CardValue::Nine as i32);
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
     fn hands_comparisons() {
         let result = (HandType::FourKind as i32) > (HandType::HighCard as i32);
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
     fn test_handtype() {
         let cards =
             vec![CardValue::Ace, CardValue::Ace, CardValue::Eight, CardValue::Ace, CardValue::Ace];
-        let hand = Hand { cards: cards, bid: 0, handtype: None, power: 0 };
+        let hand = Hand { cards, bid: 0, handtype: None, power: 0 };
         let handtype = hand.get_handtype().expect("Could not determine hand type");
 
         assert_eq!(handtype, HandType::FourKind);
@@ -193,7 +193,7 @@ mod tests {
             CardValue::Ace,
             CardValue::Ace,
         ];
-        let hand = Hand { cards: cards, bid: 0, handtype: None, power: 0 };
+        let hand = Hand { cards, bid: 0, handtype: None, power: 0 };
         let handtype = hand.get_handtype().expect("Could not determine hand type");
 
         assert_eq!(handtype, HandType::ThreeKind);
@@ -205,7 +205,7 @@ mod tests {
             CardValue::Eight,
             CardValue::Ace,
         ];
-        let hand = Hand { cards: cards, bid: 0, handtype: None, power: 0 };
+        let hand = Hand { cards, bid: 0, handtype: None, power: 0 };
         let handtype = hand.get_handtype().expect("Could not determine hand type");
 
         assert_eq!(handtype, HandType::FullHouse);
@@ -217,7 +217,7 @@ mod tests {
             CardValue::Eight,
             CardValue::Ace,
         ];
-        let hand = Hand { cards: cards, bid: 0, handtype: None, power: 0 };
+        let hand = Hand { cards, bid: 0, handtype: None, power: 0 };
         let handtype = hand.get_handtype().expect("Could not determine hand type");
 
         assert_eq!(handtype, HandType::TwoPair);
@@ -228,15 +228,13 @@ mod tests {
         let cards =
             vec![CardValue::Ace, CardValue::Ace, CardValue::Eight, CardValue::Ace, CardValue::Ace];
         let hand = Hand::new(cards, 0).expect("Could not generate new hand");
-        //let assignment = hand.assign_handtype().unwrap();
-        //assert_eq!(assignment, ());
         assert_eq!(hand.handtype, Some(HandType::FourKind))
     }
 
     #[test]
     fn test_from_string() {
         let input = "32T3K 765".to_string();
-        let hand = Hand::from_string(&input);
+        let hand = Hand::from_string(&input, &input).unwrap();
         let cards = vec![
             CardValue::Three,
             CardValue::Two,
@@ -248,29 +246,34 @@ mod tests {
         assert_eq!(hand.cards, cards);
         assert_eq!(hand.bid, bid);
         assert_eq!(hand.handtype, Some(HandType::OnePair));
+
+        let input = "32T3K 765\nKK6X7 28";
+        let error = Hand::from_string(input, &input[10..]).unwrap_err();
+        assert_eq!(error.position(), Some((2, 4)));
+        let error = Hand::from_string(input, &input[..5]).unwrap_err();
+        assert_eq!(error.msg(), "Expected cards and a bid");
     }
-    fn parse_input() {
-        todo!("Implement function to parse input")
+    fn parse_input(input: &str) -> Vec<Hand> {
+        input.lines().map(|line| Hand::from_string(input, line).unwrap()).collect()
     }
 
     #[test]
     fn test_order_hands() {
-        todo!("Create vector of Hand and sort it by rank");
-        /*
-        let hands: Vec<Hand> = vec![Hand::new(), Hand::new(), Hand::new()];
-        let mut hands_order: Vec<Hand> = sort_hands(hands);
+        let mut hands = parse_input(&fs::read_to_string("inputs/2023/day_7/test1.txt").unwrap());
+        hands.sort_by_key(|hand| hand.power);
+        let bids: Vec<usize> = hands.iter().map(|hand| hand.bid).collect();
 
-        assert_eq!(hands_order.pop().unwrap().handtype, Some(HandType::FullHouse));
-        assert_eq!(hands_order.pop().unwrap().handtype, Some(HandType::FullHouse));
-        assert_eq!(hands_order.pop().unwrap().handtype, Some(HandType::FourKind));
-        assert_eq!(hands_order.pop().unwrap().handtype, Some(HandType::ThreeKind));
-        assert_eq!(hands_order.pop().unwrap().handtype, Some(HandType::OnePair));
-        */
+        assert_eq!(bids, vec![765, 28, 684, 483, 220]);
+        assert_eq!(hands.pop().unwrap().handtype, Some(HandType::FourKind));
+        assert_eq!(hands.pop().unwrap().handtype, Some(HandType::FourKind));
+        assert_eq!(hands.pop().unwrap().handtype, Some(HandType::FourKind));
+        assert_eq!(hands.pop().unwrap().handtype, Some(HandType::TwoPair));
+        assert_eq!(hands.pop().unwrap().handtype, Some(HandType::OnePair));
     }
 
+    #[test]
     fn evaluate_test() {
-        todo!("Test case");
-        let total_winnings = 0;
-        assert_eq!(total_winnings, 6440);
+        let input = fs::read_to_string("inputs/2023/day_7/test1.txt").unwrap();
+        assert_eq!(Day7b.main(&input).unwrap(), Answer::Int(5905));
     }
 }
@@ -0,0 +1,163 @@
+use super::{mark_parsed, Answer, CommandImpl, DynError, ParseError};
+use clap::Parser;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Parser, Debug)]
+pub struct Day8a;
+
+impl CommandImpl for Day8a {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let (instructions, nodes) = parse_input(input)?;
+        mark_parsed();
+        let result = find_path_length(instructions, nodes)?;
+        Ok(result.into())
+    }
+}
+
+type Nodes<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+fn parse_input(input: &str) -> Result<(Vec<char>, Nodes), ParseError> {
+    let mut nodes: Nodes = HashMap::new();
+    let parse_regex = Regex::new(r"(.*) = \((.*), (.*)\)").unwrap();
+    let mut lines = input.lines();
+    let first_line = lines.next().unwrap_or(input);
+    let instructions = first_line
+        .char_indices()
+        .map(|(i, c)| {
+            let span = &first_line[i..i + c.len_utf8()];
+            match c {
+                'L' | 'R' => Ok(c),
+                _ => Err(ParseError::at(input, span, "Only L and R are allowed as instruction")),
+            }
+        })
+        .collect::<Result<Vec<char>, _>>()?;
+    if instructions.is_empty() {
+        return Err(ParseError::at(input, first_line, "Expected a line of instructions"));
+    }
+    lines.next();
+    let mut links = Vec::new();
+    for line in lines.filter(|line| !line.trim().is_empty()) {
+        let captures = parse_regex
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "Expected a node like AAA = (BBB, CCC)"))?;
+        let [node, left, right] =
+            [1, 2, 3].map(|i| captures.get(i).expect("All groups take part").as_str().trim());
+        nodes.insert(node, (left, right));
+        links.extend([left, right]);
+    }
+    // every step has to lead to a node, so walking the network can't get stuck
+    if let Some(link) = links.into_iter().find(|link| !nodes.contains_key(link)) {
+        return Err(ParseError::at(input, link, format!("Node {link} is not defined")));
+    }
+    // the walk goes from AAA to ZZZ, e.g. the part b example has neither
+    if let Some(missing) = ["AAA", "ZZZ"].into_iter().find(|node| !nodes.contains_key(node)) {
+        return Err(ParseError::new(format!("There is no node {missing}")));
+    }
+    Ok((instructions, nodes))
+}
+
+fn find_path_length(instructions: Vec<char>, nodes: Nodes) -> Result<usize, String> {
+    let start = "AAA";
+    let end = "ZZZ";
+    let mut steps: usize = 0;
+    let mut instruction_index: usize = 0;
+    let mut current: &str = start;
+    let first_instruction = instructions[0];
+    let mut instruction = first_instruction;
+    // (node, instruction index) states already walked through, being in one again repeats forever
+    let mut seen = HashSet::new();
+    while current != end {
+        if !seen.insert((current, instruction_index)) {
+            return Err(format!("Infinite loop, {end} is never reached"));
+        }
+        match instruction {
+            'L' => {
+                current = nodes[current].0;
+            }
+            'R' => {
+                current = nodes[current].1;
+            }
+            _ => return Err("undefined instruction".to_string()),
+        }
+        if instruction_index == instructions.len() - 1 {
+            instruction_index = 0;
+        } else {
+            instruction_index += 1;
+        }
+        steps += 1;
+        instruction = instructions[instruction_index];
+    }
+    Ok(steps)
+}
+//
+//
+//
+//
+//
+//
+// Tests follow here
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_parsing() {
+        let input = "LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)"
+            .to_string();
+        let (instructions, nodes) = parse_input(&input).unwrap();
+        assert_eq!(instructions, vec!['L', 'L', 'R']);
+    }
+    #[test]
+    fn test_input1() {
+        let input = "RL
+
+        AAA = (BBB, CCC)
+        BBB = (DDD, EEE)
+        CCC = (ZZZ, GGG)
+        DDD = (DDD, DDD)
+        EEE = (EEE, EEE)
+        GGG = (GGG, GGG)
+        ZZZ = (ZZZ, ZZZ)"
+            .to_string();
+        let (instructions, nodes) = parse_input(&input).unwrap();
+        let num_steps = find_path_length(instructions, nodes);
+        assert_eq!(num_steps, Ok(2));
+    }
+    #[test]
+    fn test_input2() {
+        let input = "LLR
+
+        AAA = (BBB, BBB)
+        BBB = (AAA, ZZZ)
+        ZZZ = (ZZZ, ZZZ)"
+            .to_string();
+        let (instructions, nodes) = parse_input(&input).unwrap();
+        let num_steps = find_path_length(instructions, nodes);
+        assert_eq!(num_steps, Ok(6));
+    }
+    #[test]
+    fn test_missing_start_or_end() {
+        let error = parse_input("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap_err();
+        assert!(error.to_string().contains("There is no node AAA"));
+        assert!(parse_input("L\n\nAAA = (AAA, AAA)").is_err());
+        let test2 = fs::read_to_string("inputs/2023/day_8/test2.txt").unwrap();
+        assert!(parse_input(&test2).is_err());
+    }
+    #[test]
+    fn test_infinite_loop() {
+        // the walk gets stuck on BBB without ever passing AAA again
+        let input = "L
+
+        AAA = (BBB, BBB)
+        BBB = (BBB, BBB)
+        ZZZ = (ZZZ, ZZZ)";
+        let (instructions, nodes) = parse_input(input).unwrap();
+        assert!(find_path_length(instructions, nodes).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use super::{mark_parsed, Answer, CommandImpl, DynError, ParseError};
+use crate::utils::{graph::brent, math::crt};
+use clap::Parser;
+use log::{debug, trace};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::iter;
+
+#[derive(Parser, Debug)]
+pub struct Day8b;
+
+impl CommandImpl for Day8b {
+    /// Chinese remainder theorem over the cycle of each start, the least common multiple of the
+    /// cycle lengths on the puzzle inputs
+    const VARIANT: Option<&'static str> = Some("lcm");
+
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let (instructions, nodes) = parse_input(input)?;
+        mark_parsed();
+        let starts: Vec<&str> = nodes.keys().cloned().filter(|key| key.ends_with('A')).collect();
+        if starts.is_empty() {
+            return Err(ParseError::new("No node ends with A").into());
+        }
+        let mut per_start_results = Vec::new();
+        for start in &starts {
+            per_start_results.push(find_cycle_length_and_z(&instructions, &nodes, start)?)
+        }
+        let result = find_steps_required(&per_start_results)?;
+        Ok(result.into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Instruction {
+    L,
+    R,
+}
+
+impl Instruction {
+    fn from_char(c: char) -> Result<Instruction, String> {
+        match c {
+            'L' => Ok(Instruction::L),
+            'R' => Ok(Instruction::R),
+            _ => Err(format!("Only L and R are allowed as instruction, found {c}")),
+        }
+    }
+}
+
+type Nodes<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+fn parse_input(input: &str) -> Result<(Vec<Instruction>, Nodes), ParseError> {
+    let mut nodes: Nodes = HashMap::new();
+    let parse_regex = Regex::new(r"(.*) = \((.*), (.*)\)").unwrap();
+    let mut lines = input.lines();
+    let first_line = lines.next().unwrap_or(input);
+    let instructions = first_line
+        .char_indices()
+        .map(|(i, c)| {
+            let span = &first_line[i..i + c.len_utf8()];
+            Instruction::from_char(c).map_err(|e| ParseError::at(input, span, e))
+        })
+        .collect::<Result<Vec<Instruction>, _>>()?;
+    if instructions.is_empty() {
+        return Err(ParseError::at(input, first_line, "Expected a line of instructions"));
+    }
+    lines.next();
+    let mut links = Vec::new();
+    for line in lines.filter(|line| !line.trim().is_empty()) {
+        let captures = parse_regex
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "Expected a node like AAA = (BBB, CCC)"))?;
+        let [node, left, right] =
+            [1, 2, 3].map(|i| captures.get(i).expect("All groups take part").as_str().trim());
+        nodes.insert(node, vec![left, right]);
+        links.extend([left, right]);
+    }
+    // every step has to lead to a node, so walking the network can't get stuck
+    if let Some(link) = links.into_iter().find(|link| !nodes.contains_key(link)) {
+        return Err(ParseError::at(input, link, format!("Node {link} is not defined")));
+    }
+    Ok((instructions, nodes))
+}
+
+fn find_cycle_length_and_z(
+    instructions: &[Instruction],
+    nodes: &Nodes,
+    start: &str,
+) -> Result<(usize, usize, Vec<usize>), String> {
+    // the walk repeats once it is back at a node at the same point of the instructions
+    let next = |&(node, index): &(&str, usize)| {
+        let node = nodes[node][instructions[index] as usize];
+        (node, (index + 1) % instructions.len())
+    };
+    let cycle = brent((start, 0), next);
+    debug!(
+        "For start {} found a repeating sequence starting from step {} to step {} (length {})",
+        start,
+        cycle.start,
+        cycle.start + cycle.length,
+        cycle.length
+    );
+    let z_steps: Vec<usize> = iter::successors(Some((start, 0)), |state| Some(next(state)))
+        .enumerate()
+        .take(cycle.start + cycle.length)
+        .skip(cycle.start)
+        .filter(|(_, (node, _))| node.ends_with('Z'))
+        .map(|(step, _)| step)
+        .collect();
+    if z_steps.is_empty() {
+        return Err(format!("No node ending with Z is on the cycle reached from {start}"));
+    }
+    trace!("Detected __Z positions at {:?}", z_steps);
+    Ok((cycle.start, cycle.length, z_steps))
+}
+
+// Once on its cycle, each start is at a __Z on the steps Z_pos + Cycle_length x n, for every
+// Z_pos of a __Z on the cycle. All walks are on a __Z at the first step that solves one of these
+// congruences for every start, which the chinese remainder theorem finds. On the puzzle inputs
+// every cycle has a single __Z with Z_pos equal to Cycle_length, making this the least common
+// multiple of the cycle lengths.
+fn find_steps_required(data: &[(usize, usize, Vec<usize>)]) -> Result<u64, String> {
+    // (first step, period) of the steps on which the starts so far are all at a __Z
+    let mut solutions: Vec<(i64, i64)> = vec![(0, 1)];
+    for (_, length, z_steps) in data {
+        solutions = solutions
+            .iter()
+            .flat_map(|&solution| {
+                z_steps.iter().filter_map(move |&z| crt(&[solution, (z as i64, *length as i64)]))
+            })
+            .collect();
+    }
+    // the smallest solution can come before some walk reached its cycle
+    let earliest = data.iter().map(|&(start, ..)| start as i64).max().unwrap_or(0);
+    solutions
+        .into_iter()
+        .map(|(steps, period)| {
+            let behind = (earliest - steps).max(0);
+            let laps = behind / period + i64::from(behind % period != 0);
+            (steps + laps * period) as u64
+        })
+        .min()
+        .ok_or_else(|| "The walks never all end on __Z at the same time".to_string())
+}
@@ -1,44 +1,58 @@
-use super::{CommandImpl, DynError};
+use super::{mark_parsed, Answer, CommandImpl, DynError, ParseError};
 use clap::Parser;
+use log::{debug, info};
 use regex::Regex;
-use std::path::PathBuf;
-use std::{collections::HashMap, fs};
+use std::collections::HashMap;
 
 #[derive(Parser, Debug)]
-pub struct Day8bBruteforce {
-    #[clap(long, short)]
-    input: PathBuf,
-}
+pub struct Day8bBruteforce;
 
 impl CommandImpl for Day8bBruteforce {
-    fn main(&self) -> Result<(), DynError> {
-        let input = fs::read_to_string(&self.input).unwrap();
-        let (instructions, nodes) = parse_input(&input);
+    /// Walks every ghost step by step, the challenge input needs trillions of steps
+    const SLOW: bool = true;
+
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let (instructions, nodes) = parse_input(input)?;
+        mark_parsed();
 
-        let result = find_path_length(instructions, nodes).unwrap();
-        println!("Day8b: {result}");
-        Ok(())
+        let result = find_path_length(instructions, nodes)?;
+        Ok(result.into())
     }
 }
 
-fn parse_input(input: &str) -> (Vec<Instruction>, HashMap<&str, Vec<&str>>) {
-    let mut nodes: HashMap<&str, Vec<&str>> = HashMap::new();
+type Nodes<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+fn parse_input(input: &str) -> Result<(Vec<Instruction>, Nodes), ParseError> {
+    let mut nodes: Nodes = HashMap::new();
     let parse_regex = Regex::new(r"(.*) = \((.*), (.*)\)").unwrap();
     let mut lines = input.lines();
-    let instructions: Vec<Instruction> =
-        lines.next().unwrap().chars().map(|c| Instruction::from_char(c)).collect();
+    let first_line = lines.next().unwrap_or(input);
+    let instructions = first_line
+        .char_indices()
+        .map(|(i, c)| {
+            let span = &first_line[i..i + c.len_utf8()];
+            Instruction::from_char(c).map_err(|e| ParseError::at(input, span, e))
+        })
+        .collect::<Result<Vec<Instruction>, _>>()?;
+    if instructions.is_empty() {
+        return Err(ParseError::at(input, first_line, "Expected a line of instructions"));
+    }
     lines.next();
-    for line in lines {
-        let values = line.split_ascii_whitespace();
-        let matches: Vec<&str> = parse_regex
+    let mut links = Vec::new();
+    for line in lines.filter(|line| !line.trim().is_empty()) {
+        let captures = parse_regex
             .captures(line)
-            .unwrap()
-            .iter()
-            .map(|x| x.unwrap().as_str().trim())
-            .collect();
-        nodes.insert(matches[1], vec![matches[2], matches[3]]);
+            .ok_or_else(|| ParseError::at(input, line, "Expected a node like AAA = (BBB, CCC)"))?;
+        let [node, left, right] =
+            [1, 2, 3].map(|i| captures.get(i).expect("All groups take part").as_str().trim());
+        nodes.insert(node, vec![left, right]);
+        links.extend([left, right]);
+    }
+    // every step has to lead to a node, so walking the network can't get stuck
+    if let Some(link) = links.into_iter().find(|link| !nodes.contains_key(link)) {
+        return Err(ParseError::at(input, link, format!("Node {link} is not defined")));
     }
-    (instructions, nodes)
+    Ok((instructions, nodes))
 }
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 enum Instruction {
@@ -47,11 +61,11 @@ enum Instruction {
 }
 
 impl Instruction {
-    fn from_char(c: char) -> Instruction {
+    fn from_char(c: char) -> Result<Instruction, String> {
         match c {
-            'L' => Instruction::L,
-            'R' => Instruction::R,
-            _ => panic!("Only L and R are allowed as instruction"),
+            'L' => Ok(Instruction::L),
+            'R' => Ok(Instruction::R),
+            _ => Err(format!("Only L and R are allowed as instruction, found {c}")),
         }
     }
 }
@@ -60,13 +74,8 @@ fn find_path_length(
     instructions: Vec<Instruction>,
     nodes: HashMap<&str, Vec<&str>>,
 ) -> Result<usize, String> {
-    let starts: Vec<&str> = nodes
-        .keys()
-        .cloned()
-        .into_iter()
-        .filter(|key| key.chars().last().unwrap() == 'A')
-        .collect();
-    println!("Total number of starts: {}", starts.len());
+    let starts: Vec<&str> = nodes.keys().cloned().filter(|key| key.ends_with('A')).collect();
+    debug!("Total number of starts: {}", starts.len());
     let mut steps: usize = 0;
     let mut instruction_index: usize = 0;
     let mut currents: Vec<&str> = starts.clone();
@@ -74,12 +83,10 @@ fn find_path_length(
 
     let mut instruction = first_instruction;
     while !check_all_ends(&currents) {
-        //dbg!(&currents);
         currents = currents
             .iter()
             .map(|current| *nodes.get(current).unwrap().get(instruction as usize).unwrap())
             .collect();
-        //currents = nodes.get(&current).expect("step undefined").get(instruction as usize);
 
         if instruction_index == instructions.len() - 1 {
             instruction_index = 0;
@@ -89,18 +96,18 @@ fn find_path_length(
         steps = steps.checked_add(1).expect("Step count overflowing");
         instruction = instructions[instruction_index];
         if steps % 100000000 == 0 {
-            println!("{} M steps reached", steps / 1000000);
+            info!("{} M steps reached", steps / 1000000);
         }
         if (instruction_index == 0) & (currents == starts) {
             panic!("Infinite loop!");
         }
     }
-    return Ok(steps);
+    Ok(steps)
 }
 
 fn check_all_ends(currents: &Vec<&str>) -> bool {
-    let num_end = currents.iter().filter(|current| current.chars().last().unwrap() == 'Z').count();
-    return currents.len() == num_end;
+    let num_end = currents.iter().filter(|current| current.ends_with('Z')).count();
+    currents.len() == num_end
 }
 //
 //
@@ -121,7 +128,7 @@ mod tests {
         BBB = (AAA, ZZZ)
         ZZZ = (ZZZ, ZZZ)"
             .to_string();
-        let (instructions, nodes) = parse_input(&input);
+        let (instructions, nodes) = parse_input(&input).unwrap();
         assert_eq!(instructions.get(0).unwrap(), &Instruction::L);
         assert_eq!(instructions.get(2).unwrap(), &Instruction::R);
     }
@@ -137,7 +144,7 @@ mod tests {
         GGG = (GGG, GGG)
         ZZZ = (ZZZ, ZZZ)"
             .to_string();
-        let (instructions, nodes) = parse_input(&input);
+        let (instructions, nodes) = parse_input(&input).unwrap();
         let num_steps = find_path_length(instructions, nodes);
         assert_eq!(num_steps, Ok(2));
     }
@@ -149,7 +156,7 @@ mod tests {
         BBB = (AAA, ZZZ)
         ZZZ = (ZZZ, ZZZ)"
             .to_string();
-        let (instructions, nodes) = parse_input(&input);
+        let (instructions, nodes) = parse_input(&input).unwrap();
         let num_steps = find_path_length(instructions, nodes);
         assert_eq!(num_steps, Ok(6));
     }
@@ -166,7 +173,7 @@ mod tests {
     22Z = (22B, 22B)
     XXX = (XXX, XXX)"
             .to_string();
-        let (instructions, nodes) = parse_input(&input);
+        let (instructions, nodes) = parse_input(&input).unwrap();
         let num_steps = find_path_length(instructions, nodes);
         assert_eq!(num_steps, Ok(6));
     }
@@ -0,0 +1,229 @@
+use super::{mark_parsed, Answer, CommandImpl, DynError, ParseError};
+use crate::utils::{
+    graph::brent,
+    math::{crt, lcm},
+};
+use clap::Parser;
+use log::{debug, trace};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::iter;
+
+#[derive(Parser, Debug)]
+pub struct Day8bsbf;
+
+impl CommandImpl for Day8bsbf {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let (instructions, nodes) = parse_input(input)?;
+        mark_parsed();
+        let starts: Vec<&str> = nodes.keys().cloned().filter(|key| key.ends_with('A')).collect();
+        if starts.is_empty() {
+            return Err(ParseError::new("No node ends with A").into());
+        }
+        let mut per_start_results = Vec::new();
+        for start in &starts {
+            per_start_results.push(find_cycle_length_and_z(&instructions, &nodes, start)?)
+        }
+        let mut combined_zfunc = per_start_results.pop().expect("There is at least one start");
+        for zfunc in per_start_results {
+            combined_zfunc = ZFunc::combine_z_functs(&combined_zfunc, &zfunc)?;
+        }
+        let steps = combined_zfunc.first_z().expect("Combined functions have a __Z");
+        Ok(steps.into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Instruction {
+    L,
+    R,
+}
+
+impl Instruction {
+    fn from_char(c: char) -> Result<Instruction, String> {
+        match c {
+            'L' => Ok(Instruction::L),
+            'R' => Ok(Instruction::R),
+            _ => Err(format!("Only L and R are allowed as instruction, found {c}")),
+        }
+    }
+}
+// The steps on which a walk is at a __Z: those in `tail`, and once it is on its cycle from step
+// `start` on, those in `cycle` plus any multiple of `cycle_length`
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct ZFunc {
+    start: u64,
+    cycle_length: u64,
+    // sorted, all before `start`
+    tail: Vec<u64>,
+    // sorted, all in `start..start + cycle_length`
+    cycle: Vec<u64>,
+}
+
+impl ZFunc {
+    // the steps both are on a __Z, an error if there are none
+    fn combine_z_functs(func_a: &ZFunc, func_b: &ZFunc) -> Result<Self, String> {
+        let start = func_a.start.max(func_b.start);
+        // before both are on their cycles, check each __Z of one against the other
+        let mut tail: Vec<u64> = func_a
+            .z_steps_before(start)
+            .filter(|&step| func_b.is_z(step))
+            .chain(func_b.z_steps_before(start).filter(|&step| func_a.is_z(step)))
+            .collect();
+        tail.sort();
+        tail.dedup();
+
+        // from then on, a step is a __Z for both if it solves the congruences of one pair of
+        // their cycle __Zs
+        let cycle_length = lcm(func_a.cycle_length, func_b.cycle_length)
+            .ok_or("The combined cycle length overflows")?;
+        let wide = |n: u64| i64::try_from(n).map_err(|_| format!("{n} doesn't fit an i64"));
+        let mut cycle = Vec::new();
+        for &z_a in &func_a.cycle {
+            for &z_b in &func_b.cycle {
+                let congruences = [
+                    (wide(z_a)?, wide(func_a.cycle_length)?),
+                    (wide(z_b)?, wide(func_b.cycle_length)?),
+                ];
+                if let Some((first, _)) = crt(&congruences) {
+                    // the solution in start..start + cycle_length
+                    let phase = (first as u64 + cycle_length - start % cycle_length) % cycle_length;
+                    cycle.push(start + phase);
+                }
+            }
+        }
+        cycle.sort();
+        cycle.dedup();
+
+        if tail.is_empty() && cycle.is_empty() {
+            return Err(format!("{func_a:?} and {func_b:?} are never on a __Z together"));
+        }
+        Ok(ZFunc { start, cycle_length, tail, cycle })
+    }
+
+    fn first_z(&self) -> Option<u64> {
+        self.tail.first().or(self.cycle.first()).copied()
+    }
+
+    fn is_z(&self, step: u64) -> bool {
+        if step < self.start {
+            self.tail.contains(&step)
+        } else {
+            let phase = (step - self.start) % self.cycle_length;
+            self.cycle.contains(&(self.start + phase))
+        }
+    }
+
+    // the __Z steps before `until`, in order
+    fn z_steps_before(&self, until: u64) -> impl Iterator<Item = u64> + '_ {
+        let laps = (0..)
+            .map(|lap| lap * self.cycle_length)
+            .take_while(move |&offset| self.start + offset < until);
+        self.tail
+            .iter()
+            .copied()
+            .chain(laps.flat_map(|offset| self.cycle.iter().map(move |z| z + offset)))
+            .take_while(move |&step| step < until)
+    }
+}
+
+type Nodes<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+fn parse_input(input: &str) -> Result<(Vec<Instruction>, Nodes), ParseError> {
+    let mut nodes: Nodes = HashMap::new();
+    let parse_regex = Regex::new(r"(.*) = \((.*), (.*)\)").unwrap();
+    let mut lines = input.lines();
+    let first_line = lines.next().unwrap_or(input);
+    let instructions = first_line
+        .char_indices()
+        .map(|(i, c)| {
+            let span = &first_line[i..i + c.len_utf8()];
+            Instruction::from_char(c).map_err(|e| ParseError::at(input, span, e))
+        })
+        .collect::<Result<Vec<Instruction>, _>>()?;
+    if instructions.is_empty() {
+        return Err(ParseError::at(input, first_line, "Expected a line of instructions"));
+    }
+    lines.next();
+    let mut links = Vec::new();
+    for line in lines.filter(|line| !line.trim().is_empty()) {
+        let captures = parse_regex
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "Expected a node like AAA = (BBB, CCC)"))?;
+        let [node, left, right] =
+            [1, 2, 3].map(|i| captures.get(i).expect("All groups take part").as_str().trim());
+        nodes.insert(node, vec![left, right]);
+        links.extend([left, right]);
+    }
+    // every step has to lead to a node, so walking the network can't get stuck
+    if let Some(link) = links.into_iter().find(|link| !nodes.contains_key(link)) {
+        return Err(ParseError::at(input, link, format!("Node {link} is not defined")));
+    }
+    Ok((instructions, nodes))
+}
+
+fn find_cycle_length_and_z(
+    instructions: &[Instruction],
+    nodes: &Nodes,
+    start: &str,
+) -> Result<ZFunc, String> {
+    // the walk repeats once it is back at a node at the same point of the instructions
+    let next = |&(node, index): &(&str, usize)| {
+        let node = nodes[node][instructions[index] as usize];
+        (node, (index + 1) % instructions.len())
+    };
+    let cycle = brent((start, 0), next);
+    debug!(
+        "For start {} found a repeating sequence starting from step {} to step {} (length {})",
+        start,
+        cycle.start,
+        cycle.start + cycle.length,
+        cycle.length
+    );
+    // every step after the cycle's end repeats one of these
+    let (tail, cycle_z): (Vec<u64>, Vec<u64>) =
+        iter::successors(Some((start, 0)), |state| Some(next(state)))
+            .take(cycle.start + cycle.length)
+            .enumerate()
+            .filter(|(_, (node, _))| node.ends_with('Z'))
+            .map(|(step, _)| step as u64)
+            .partition(|&step| step < cycle.start as u64);
+    if tail.is_empty() && cycle_z.is_empty() {
+        return Err(format!("No node ending with Z is reached from {start}"));
+    }
+    trace!("__Z positions at {tail:?} before the cycle and {cycle_z:?} on it");
+    Ok(ZFunc { start: cycle.start as u64, cycle_length: cycle.length as u64, tail, cycle: cycle_z })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn zfunc(start: u64, cycle_length: u64, tail: &[u64], cycle: &[u64]) -> ZFunc {
+        ZFunc { start, cycle_length, tail: tail.to_vec(), cycle: cycle.to_vec() }
+    }
+
+    #[test]
+    fn test_combine_z_functs() {
+        let combine = |a: &ZFunc, b: &ZFunc| ZFunc::combine_z_functs(a, b);
+        // the second example: 11A is at 11Z every other step, 22A on steps 3 and 6 of 6
+        let combined = combine(&zfunc(1, 2, &[], &[2]), &zfunc(1, 6, &[], &[3, 6])).unwrap();
+        assert_eq!(combined, zfunc(1, 6, &[], &[6]));
+        assert_eq!(combined.first_z(), Some(6));
+        assert_eq!(combine(&zfunc(0, 4, &[], &[2]), &zfunc(0, 6, &[], &[4])).unwrap().cycle, [10]);
+        // a __Z before the cycle only counts once
+        let combined = combine(&zfunc(3, 2, &[1], &[4]), &zfunc(0, 1, &[], &[0])).unwrap();
+        assert_eq!((combined.tail, combined.cycle), (vec![1], vec![4]));
+        let combined = combine(&zfunc(5, 3, &[1], &[6]), &zfunc(0, 2, &[], &[1])).unwrap();
+        assert_eq!((combined.first_z(), combined.tail), (Some(1), vec![1]));
+        // even and odd steps never meet
+        assert!(combine(&zfunc(0, 2, &[], &[0]), &zfunc(0, 2, &[], &[1])).is_err());
+    }
+
+    #[test]
+    fn test_example() {
+        let input = fs::read_to_string("inputs/2023/day_8/test2.txt").unwrap();
+        assert_eq!(Day8bsbf.main(&input).unwrap(), Answer::Int(6));
+    }
+}
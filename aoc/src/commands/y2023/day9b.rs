@@ -0,0 +1,66 @@
+use super::{parse_at, Answer, CommandImpl, DynError, ParseError};
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+pub struct Day9b;
+
+impl CommandImpl for Day9b {
+    fn main(&self, input: &str) -> Result<Answer, DynError> {
+        let mut result = 0;
+
+        for line in input.lines() {
+            let data_sequence = Data::new(input, line)?;
+            result += data_sequence.prediction;
+        }
+        Ok(result.into())
+    }
+}
+
+struct Data {
+    measurement: Vec<i64>,
+    differentiations: Vec<Vec<i64>>,
+    prediction: i64,
+}
+
+impl Data {
+    /// Parse a line of measurements, which has to be a slice of `input`
+    pub fn new(input: &str, line: &str) -> Result<Self, ParseError> {
+        let measurement = line
+            .split_ascii_whitespace()
+            .map(|x| parse_at(input, x))
+            .collect::<Result<Vec<i64>, _>>()?;
+        let differentations: Vec<Vec<i64>> = calc_differentiations(&measurement);
+        // extrapolating needs a row of zeros that still has a value
+        if differentations.iter().any(Vec::is_empty) {
+            return Err(ParseError::at(input, line, "Differences never become all zero"));
+        }
+        let prediction = generate_previous(&differentations);
+
+        Ok(Data { measurement, differentiations: differentations, prediction })
+    }
+}
+
+fn calc_differentiations(measurement: &[i64]) -> Vec<Vec<i64>> {
+    let mut differentiations: Vec<Vec<i64>> = Vec::new();
+    differentiations.push(measurement.to_vec());
+    while differentiations.last().unwrap().iter().filter(|x| *x != &0i64).count() > 0 {
+        differentiations.push(
+            differentiations
+                .last()
+                .unwrap()
+                .array_windows()
+                .map(|window: &[i64; 2]| window.get(1).unwrap() - window.first().unwrap())
+                .collect(),
+        )
+    }
+    differentiations
+}
+
+//part B
+fn generate_previous(differentiations: &[Vec<i64>]) -> i64 {
+    let mut result: i64 = 0;
+    for differentation in differentiations.iter().rev() {
+        result = differentation.first().unwrap() - result;
+    }
+    result
+}
@@ -0,0 +1,51 @@
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Writes log records to stderr, keeping stdout for answers
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+// warnings are shown by default, each -v adds a level and -q leaves only errors
+pub fn level_filter(verbose: u8, quiet: bool) -> LevelFilter {
+    if quiet {
+        return LevelFilter::Error;
+    }
+    match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+pub fn init(level: LevelFilter) {
+    log::set_logger(&LOGGER).expect("Logger is only initialised once");
+    log::set_max_level(level);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_filter() {
+        assert_eq!(level_filter(0, false), LevelFilter::Warn);
+        assert_eq!(level_filter(2, false), LevelFilter::Debug);
+        assert_eq!(level_filter(5, false), LevelFilter::Trace);
+        assert_eq!(level_filter(0, true), LevelFilter::Error);
+    }
+}
@@ -1,51 +1,162 @@
 #![feature(array_windows)]
+pub mod answer;
+pub mod answers;
+pub mod bench;
 #[allow(unused)]
 pub mod commands;
+pub mod logger;
+pub mod output;
+pub mod runner;
 pub mod utils;
 
 use commands::*;
-use enum_dispatch::enum_dispatch;
 
-use clap::Parser;
+use clap::{value_parser, Arg, ArgAction, Command, FromArgMatches, Parser, Subcommand};
+use output::{Format, Record};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
 
 #[derive(Parser, Debug)]
-struct Opts {
-    #[clap(subcommand)]
-    subcommand: SubCommand,
+enum Builtin {
+    #[clap(alias = "run-all")]
+    All(runner::All),
+    Verify(runner::Verify),
+    List(runner::List),
 }
 
-#[enum_dispatch(CommandImpl)]
-#[derive(Parser, Debug)]
-enum SubCommand {
-    Day0(day0::Day0),
-    Day1a(day1a::Day1a),
-    Day1b(day1b::Day1b),
-    Day2a(day2a::Day2a),
-    Day2b(day2b::Day2b),
-    Day3a(day3a::Day3a),
-    Day3b(day3b::Day3b),
-    Day4a(day4a::Day4a),
-    Day4b(day4b::Day4b),
-    Day5a(day5a::Day5a),
-    Day5b(day5b::Day5b),
-    Day5b2(day5b2::Day5b2),
-    Day6a(day6a::Day6a),
-    Day6b(day6b::Day6b),
-    Day7a(day7a::Day7a),
-    Day7b(day7b::Day7b),
-    Day8a(day8a::Day8a),
-    Day8b(day8b::Day8b),
-    Day8bsbf(day8b_smartbf::Day8bsbf),
-    Day8bBruteforce(day8b_bruteforce::Day8bBruteforce),
-    Day9a(day9a::Day9a),
-    Day9b(day9b::Day9b),
-    Day10a(day10a::Day10a),
-    Day10b(day10b::Day10b),
-    Day11a(day11a::Day11a),
-    Day11b(day11b::Day11b),
+impl Builtin {
+    fn main(&self, year: u32, format: Format) -> Result<(), DynError> {
+        match self {
+            Builtin::All(all) => all.main(year, format),
+            Builtin::Verify(verify) => verify.main(year, format),
+            Builtin::List(list) => list.main(year, format),
+        }
+    }
+}
+
+// Only the solutions of one year are registered as subcommands, so the year is needed before the
+// command line can be parsed. `--year` is still declared on `cli` for help and validation.
+fn requested_year(args: &[String]) -> Option<u32> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--year" {
+            return args.next()?.parse().ok();
+        }
+        if let Some(year) = arg.strip_prefix("--year=") {
+            return year.parse().ok();
+        }
+    }
+    None
 }
-fn main() -> Result<(), DynError> {
-    let opts = Opts::parse();
 
-    opts.subcommand.main()
+fn cli(year: u32) -> Command {
+    let verbose = Arg::new("verbose")
+        .long("verbose")
+        .short('v')
+        .action(ArgAction::Count)
+        .global(true)
+        .help("Show diagnostics, repeat for more detail (-vv debug, -vvv trace)");
+    let quiet = Arg::new("quiet")
+        .long("quiet")
+        .short('q')
+        .action(ArgAction::SetTrue)
+        .global(true)
+        .conflicts_with("verbose")
+        .help("Only show errors");
+    let format = Arg::new("format")
+        .long("format")
+        .value_name("FORMAT")
+        .value_parser(value_parser!(Format))
+        .default_value("text")
+        .global(true)
+        .help("Output format");
+    let year_arg = Arg::new("year")
+        .long("year")
+        .value_name("YEAR")
+        .value_parser(value_parser!(u32))
+        .global(true)
+        .help(format!("Puzzle year to run solutions from [default: {year}]"));
+    let cmd = Command::new(env!("CARGO_PKG_NAME"))
+        .subcommand_required(true)
+        .args([verbose, quiet, format, year_arg]);
+    Builtin::augment_subcommands(cmd).subcommands(
+        SOLUTIONS
+            .iter()
+            .filter(|solution| solution.year == year && solution.is_default)
+            .map(Solution::command),
+    )
+}
+
+// errors are printed with Display, so parse errors show the offending line
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), DynError> {
+    let args: Vec<String> = std::env::args().collect();
+    let year = requested_year(&args).unwrap_or(runner::config().year);
+    let years = years();
+    if !years.contains(&year) {
+        let years: Vec<String> = years.iter().map(u32::to_string).collect();
+        return Err(format!("No solutions for {year}, available: {}", years.join(", ")).into());
+    }
+
+    let matches = cli(year).get_matches_from(&args);
+    let (name, sub_matches) = matches.subcommand().expect("Subcommand is required");
+    // global args are propagated down to the subcommand
+    logger::init(logger::level_filter(
+        sub_matches.get_count("verbose"),
+        sub_matches.get_flag("quiet"),
+    ));
+
+    let format = *sub_matches.get_one::<Format>("format").expect("Format has a default");
+
+    if Builtin::has_subcommand(name) {
+        return Builtin::from_arg_matches(&matches)?.main(year, format);
+    }
+    let solution =
+        find(year, name).expect("Only registered solutions are subcommands").selected(sub_matches);
+    let input = match solution.input(sub_matches) {
+        Ok(input) => input,
+        Err(e) => {
+            if format == Format::Json {
+                Record::new(solution, None, None, &Err(e.to_string())).print();
+            }
+            return Err(e);
+        }
+    };
+    if sub_matches.get_flag("all-variants") {
+        return runner::compare_variants(solution, sub_matches, &input, format);
+    }
+    if let Some(&iterations) = sub_matches.get_one::<usize>("bench") {
+        let report = bench::bench(solution, sub_matches, &input, iterations)?;
+        match format {
+            Format::Text => report.print(solution),
+            Format::Json => report.print_json(solution, &input.name),
+        }
+        if let Some(csv) = sub_matches.get_one::<PathBuf>("csv") {
+            report.append_csv(csv, solution, &input.name)?;
+        }
+        return Ok(());
+    }
+
+    let start = Instant::now();
+    let outcome = solution.solve(sub_matches, &input);
+    let elapsed = start.elapsed();
+    match format {
+        Format::Text => println!("{}: {}", solution.label(), outcome?),
+        Format::Json => {
+            let outcome = outcome.map_err(|e| format!("{e:#}"));
+            Record::new(solution, Some(input.name), Some(elapsed), &outcome).print();
+            outcome?;
+        }
+    }
+    Ok(())
 }
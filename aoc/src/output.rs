@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::commands::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Human readable lines and tables
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+/// Result of running one solution on one input, printed as a JSON line
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: String,
+    pub solution: &'static str,
+    pub variant: &'static str,
+    /// Path of the input file, `-` for stdin
+    pub input: Option<String>,
+    pub answer: Option<String>,
+    pub elapsed_ns: Option<u128>,
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<&'static str>,
+}
+
+impl Record {
+    pub fn new(
+        solution: &Solution,
+        input: Option<String>,
+        elapsed: Option<Duration>,
+        outcome: &Result<Answer, String>,
+    ) -> Self {
+        let (answer, error) = match outcome {
+            Ok(answer) => (Some(answer.to_string()), None),
+            Err(e) => (None, Some(e.clone())),
+        };
+        Record {
+            year: solution.year,
+            day: solution.day,
+            part: solution.part.to_string(),
+            solution: solution.name,
+            variant: solution.variant,
+            input,
+            answer,
+            elapsed_ns: elapsed.map(|elapsed| elapsed.as_nanos()),
+            error,
+            expected: None,
+            status: None,
+        }
+    }
+
+    pub fn print(&self) {
+        println!("{}", serde_json::to_string(self).expect("Records always serialize"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_json() {
+        let solution = crate::commands::find(2023, "day9b").unwrap();
+        let record = Record::new(
+            solution,
+            Some("inputs/day_9/test1.txt".to_string()),
+            Some(Duration::from_micros(3)),
+            &Ok(Answer::Int(2)),
+        );
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"year":2023,"day":9,"part":"b","solution":"day9b","variant":"default","input":"inputs/day_9/test1.txt","answer":"2","elapsed_ns":3000,"error":null}"#
+        );
+
+        let mut record = Record::new(solution, None, None, &Err("panicked".to_string()));
+        record.status = Some("fail");
+        let json: serde_json::Value = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["error"], "panicked");
+        assert_eq!(json["answer"], serde_json::Value::Null);
+        assert_eq!(json["status"], "fail");
+    }
+}
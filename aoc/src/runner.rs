@@ -0,0 +1,377 @@
+use aoc_config::Config;
+use clap::{ArgMatches, Parser};
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use crate::answers::{answers_path, Answers};
+use crate::commands::{Answer, DynError, Solution, SOLUTIONS};
+use crate::output::{Format, Record};
+use crate::utils::input::{slurp_stdin, slurp_string, STDIN_SOURCE};
+use serde_json::json;
+
+/// Run every registered day against its challenge input and print a summary
+#[derive(Parser, Debug)]
+pub struct All {
+    /// Only run solutions for this day
+    #[clap(long, short)]
+    day: Option<u32>,
+}
+
+impl All {
+    pub fn main(&self, year: u32, format: Format) -> Result<(), DynError> {
+        let mut results = Vec::new();
+        for solution in
+            SOLUTIONS.iter().filter(|solution| solution.year == year && solution.is_default)
+        {
+            if self.day.map_or(true, |day| day == solution.day) {
+                let run = match resolve_input(year, solution.day, None) {
+                    Ok(input) => {
+                        let (elapsed, outcome) = run_timed(solution, &input);
+                        Run { solution, input: Some(input), elapsed, outcome }
+                    }
+                    Err(e) => Run {
+                        solution,
+                        input: None,
+                        elapsed: Duration::ZERO,
+                        outcome: Err(e.to_string()),
+                    },
+                };
+                results.push(run);
+            }
+        }
+
+        match format {
+            Format::Text => print_summary(&results),
+            Format::Json => results.iter().for_each(|run| run.record().print()),
+        }
+        let failed = results.iter().filter(|run| run.outcome.is_err()).count();
+        if failed > 0 {
+            return Err(format!("{failed} of {} solutions failed", results.len()).into());
+        }
+        Ok(())
+    }
+}
+
+/// Check every solution against the answers recorded in aoc/answers/<year>.toml
+#[derive(Parser, Debug)]
+pub struct Verify {
+    /// Only verify solutions for this day
+    #[clap(long, short)]
+    day: Option<u32>,
+}
+
+impl Verify {
+    pub fn main(&self, year: u32, format: Format) -> Result<(), DynError> {
+        let answers = Answers::load(&answers_path(year))?;
+        let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+        if format == Format::Text {
+            println!("{:>4}  {:<4}  {:<12}  {:>12}  Status", "Day", "Part", "Input", "Time");
+        }
+        for solution in
+            SOLUTIONS.iter().filter(|solution| solution.year == year && solution.is_default)
+        {
+            if self.day.map_or(false, |day| day != solution.day) {
+                continue;
+            }
+            let mut recorded = answers.for_part(solution.day, solution.part).peekable();
+            if recorded.peek().is_none() {
+                missing += 1;
+                match format {
+                    Format::Text => println!(
+                        "{:>4}  {:<4}  {:<12}  {:>12}  missing",
+                        solution.day, solution.part, "", ""
+                    ),
+                    Format::Json => {
+                        let mut record = Record::new(solution, None, None, &Err("missing".into()));
+                        record.status = Some("missing");
+                        record.print();
+                    }
+                }
+            }
+            for expected in recorded {
+                let input = inputs_dir(year, solution.day).join(format!("{}.txt", expected.input));
+                let (elapsed, outcome) = run_timed(solution, &input);
+                let pass = matches!(&outcome, Ok(answer) if answer.to_string() == expected.answer);
+                if pass {
+                    passed += 1;
+                } else {
+                    failed += 1;
+                }
+                match format {
+                    Format::Text => {
+                        let status = match &outcome {
+                            Ok(_) if pass => "pass".to_string(),
+                            Ok(answer) => {
+                                format!("FAIL: expected {}, got {answer}", expected.answer)
+                            }
+                            Err(e) => format!("FAIL: {e}"),
+                        };
+                        println!(
+                            "{:>4}  {:<4}  {:<12}  {:>12}  {status}",
+                            solution.day,
+                            solution.part.to_string(),
+                            expected.input,
+                            format!("{elapsed:.3?}")
+                        );
+                    }
+                    Format::Json => {
+                        let input = input.display().to_string();
+                        let mut record =
+                            Record::new(solution, Some(input), Some(elapsed), &outcome);
+                        record.expected = Some(expected.answer.clone());
+                        record.status = Some(if pass { "pass" } else { "fail" });
+                        record.print();
+                    }
+                }
+            }
+        }
+
+        if format == Format::Text {
+            println!("{passed} passed, {failed} failed, {missing} without recorded answers");
+        }
+        if failed > 0 {
+            return Err(format!("{failed} answers did not match").into());
+        }
+        Ok(())
+    }
+}
+
+/// List the registered solutions and their variants
+#[derive(Parser, Debug)]
+pub struct List {
+    /// Only list solutions for this day
+    #[clap(long, short)]
+    day: Option<u32>,
+}
+
+impl List {
+    pub fn main(&self, year: u32, format: Format) -> Result<(), DynError> {
+        for solution in
+            SOLUTIONS.iter().filter(|solution| solution.year == year && solution.is_default)
+        {
+            if self.day.map_or(false, |day| day != solution.day) {
+                continue;
+            }
+            match format {
+                Format::Text => {
+                    let variants: Vec<&str> =
+                        solution.variants().map(|variant| variant.variant).collect();
+                    println!("{:<8}  {}", solution.name, variants.join(", "));
+                }
+                Format::Json => {
+                    for variant in solution.variants() {
+                        let listing = json!({
+                            "year": variant.year,
+                            "day": variant.day,
+                            "part": variant.part.to_string(),
+                            "solution": variant.name,
+                            "variant": variant.variant,
+                            "default": variant.is_default,
+                        });
+                        println!("{listing}");
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Run every variant of a solution's part on the same input and check that they agree. Slow
+/// variants only run with `--include-slow`.
+pub fn compare_variants(
+    solution: &Solution,
+    matches: &ArgMatches,
+    input: &Input,
+    format: Format,
+) -> Result<(), DynError> {
+    let mut answers: Vec<(&str, String)> = Vec::new();
+    for variant in solution.variants() {
+        if variant.slow && !matches.get_flag("include-slow") {
+            if format == Format::Text {
+                println!("{} [{}]: skipped, it is slow", variant.label(), variant.variant);
+            }
+            continue;
+        }
+        let start = Instant::now();
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| variant.solve(matches, input)));
+        let elapsed = start.elapsed();
+        let outcome = match outcome {
+            Ok(outcome) => outcome.map_err(|e| format!("{e:#}")),
+            Err(_) => Err("panicked".to_string()),
+        };
+        match format {
+            Format::Text => match &outcome {
+                Ok(answer) => {
+                    println!("{} [{}]: {answer} ({elapsed:.3?})", variant.label(), variant.variant)
+                }
+                Err(e) => println!("{} [{}]: error: {e}", variant.label(), variant.variant),
+            },
+            Format::Json => {
+                Record::new(variant, Some(input.name.clone()), Some(elapsed), &outcome).print()
+            }
+        }
+        answers.push((
+            variant.variant,
+            outcome.map_or_else(|e| format!("error: {e}"), |answer| answer.to_string()),
+        ));
+    }
+
+    if answers.windows(2).any(|pair| pair[0].1 != pair[1].1) {
+        let answers: Vec<String> =
+            answers.iter().map(|(variant, answer)| format!("{variant}={answer}")).collect();
+        return Err(format!("Variants disagree: {}", answers.join(", ")).into());
+    }
+    Ok(())
+}
+
+struct Run {
+    solution: &'static Solution,
+    input: Option<PathBuf>,
+    elapsed: Duration,
+    outcome: Result<Answer, String>,
+}
+
+impl Run {
+    fn record(&self) -> Record {
+        let input = self.input.as_ref().map(|input| input.display().to_string());
+        Record::new(self.solution, input, Some(self.elapsed), &self.outcome)
+    }
+}
+
+/// Workspace layout from `aoc.toml`, loaded on first use
+pub fn config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(|| {
+        Config::discover(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap_or_else(|e| panic!("{e}"))
+    })
+}
+
+pub fn inputs_dir(year: u32, day: u32) -> PathBuf {
+    config().inputs_dir(year, day)
+}
+
+const DEFAULT_INPUT: &str = "challenge1";
+const STDIN_INPUT: &str = "-";
+
+/// Puzzle input together with where it was read from
+pub struct Input {
+    /// Path of the input file, `-` for stdin
+    pub name: String,
+    pub content: String,
+}
+
+impl Input {
+    fn stdin() -> Result<Self, DynError> {
+        Ok(Input { name: STDIN_INPUT.to_string(), content: slurp_stdin()? })
+    }
+
+    pub(crate) fn file(path: &Path) -> Result<Self, DynError> {
+        Ok(Input { name: path.display().to_string(), content: slurp_string(path)? })
+    }
+
+    /// Name for error messages pointing into the input
+    pub fn source(&self) -> &str {
+        if self.name == STDIN_INPUT {
+            STDIN_SOURCE
+        } else {
+            &self.name
+        }
+    }
+}
+
+/// Read the puzzle input for `--input`. `-` reads stdin, as does leaving it out while data is piped
+/// in; anything else is resolved to a file with [`resolve_input`].
+pub fn read_input(year: u32, day: u32, input: Option<&str>) -> Result<Input, DynError> {
+    match input {
+        Some(STDIN_INPUT) => Input::stdin(),
+        Some(input) => Input::file(&resolve_input(year, day, Some(input))?),
+        None => {
+            // an empty pipe (e.g. stdin redirected from /dev/null) falls back to the default file
+            if !io::stdin().is_terminal() {
+                let input = Input::stdin()?;
+                if !input.content.is_empty() {
+                    return Ok(input);
+                }
+            }
+            Input::file(&resolve_input(year, day, None)?)
+        }
+    }
+}
+
+/// Resolve `--input` to a file: either an existing path or the name of a file in the day's inputs
+/// directory (`test1` or `test1.txt`). Without an input the day's challenge input is used.
+pub fn resolve_input(year: u32, day: u32, input: Option<&str>) -> Result<PathBuf, DynError> {
+    let input = input.unwrap_or(DEFAULT_INPUT);
+    let path = Path::new(input);
+    if path.is_file() {
+        return Ok(path.to_path_buf());
+    }
+
+    let dir = inputs_dir(year, day);
+    let candidate = match path.extension() {
+        Some(_) => dir.join(input),
+        None => dir.join(format!("{input}.txt")),
+    };
+    if candidate.is_file() {
+        return Ok(candidate);
+    }
+    let available = available_inputs(&dir);
+    let available = if available.is_empty() { "none".to_string() } else { available.join(", ") };
+    Err(format!("No input {input} for day {day}, available in {}: {available}", dir.display())
+        .into())
+}
+
+// names of the input files in a day's directory, without their extension
+fn available_inputs(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "txt"))
+        .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+        .collect();
+    names.sort();
+    names
+}
+
+// runs a solution, turning both errors and panics into an error message
+fn run_timed(solution: &Solution, input: &Path) -> (Duration, Result<Answer, String>) {
+    if !input.exists() {
+        return (Duration::ZERO, Err(format!("missing input {}", input.display())));
+    }
+    let start = Instant::now();
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| solution.run_with_input(input)));
+    let elapsed = start.elapsed();
+    match outcome {
+        Ok(Ok(answer)) => (elapsed, Ok(answer)),
+        // one line, so parse errors don't break up the tables
+        Ok(Err(e)) => (elapsed, Err(format!("{e:#}"))),
+        Err(_) => (elapsed, Err("panicked".to_string())),
+    }
+}
+
+fn print_summary(results: &[Run]) {
+    println!();
+    println!("{:>4}  {:<4}  {:<18}  {:>12}  Answer", "Day", "Part", "Solution", "Time");
+    for run in results {
+        let answer = match &run.outcome {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {e}"),
+        };
+        println!(
+            "{:>4}  {:<4}  {:<18}  {:>12}  {}",
+            run.solution.day,
+            run.solution.part.to_string(),
+            run.solution.name,
+            format!("{:.3?}", run.elapsed),
+            answer
+        );
+    }
+}
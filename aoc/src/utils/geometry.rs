@@ -0,0 +1,205 @@
+//! Points and directions on the plane. `y` grows downwards, as the rows of an input do, so north is
+//! `y - 1`.
+
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position, or the offset between two. Coordinates are signed so stepping off the top or left
+/// edge of a grid gives a point the grid simply doesn't contain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point { x, y }
+    }
+
+    /// The point one step towards `direction`
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+
+    /// Distance moving only horizontally or vertically
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance when diagonal steps count as one, like a king moves
+    pub fn chebyshev(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// A compass direction, including the diagonals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// The 4 directions along the axes, clockwise starting north
+    pub const CARDINAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+    /// All 8 directions, clockwise starting north
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+
+    /// The offset of one step in this direction
+    pub const fn delta(self) -> Point {
+        use Direction::*;
+        match self {
+            N => Point::new(0, -1),
+            NE => Point::new(1, -1),
+            E => Point::new(1, 0),
+            SE => Point::new(1, 1),
+            S => Point::new(0, 1),
+            SW => Point::new(-1, 1),
+            W => Point::new(-1, 0),
+            NW => Point::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Self::CARDINAL.contains(&self)
+    }
+
+    /// Rotate by `eighths` of a full turn, clockwise for positive values
+    fn rotate(self, eighths: i64) -> Direction {
+        Self::ALL[(self as i64 + eighths).rem_euclid(8) as usize]
+    }
+
+    /// A quarter turn clockwise
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// A quarter turn counterclockwise
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-2)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.rotate(4)
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point() {
+        let a = Point::new(2, -3);
+        let b = Point::new(-1, 1);
+        assert_eq!(a + b, Point::new(1, -2));
+        assert_eq!(a - b, Point::new(3, -4));
+        assert_eq!(-a, Point::new(-2, 3));
+        assert_eq!(b * 3, Point::new(-3, 3));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.manhattan(a), 0);
+        assert_eq!(Point::ORIGIN.step(Direction::N), Point::new(0, -1));
+        assert_eq!(Point::ORIGIN.step(Direction::SW), Point::new(-1, 1));
+        assert_eq!(Point::new(i64::MIN, 0).manhattan(Point::new(i64::MAX, 0)), u64::MAX);
+    }
+
+    #[test]
+    fn test_direction() {
+        use Direction::*;
+        assert_eq!(N.turn_right(), E);
+        assert_eq!(N.turn_left(), W);
+        assert_eq!(W.turn_right(), N);
+        assert_eq!(NW.turn_right(), NE);
+        assert_eq!(NE.turn_left(), NW);
+        assert_eq!(SE.reverse(), NW);
+        for direction in Direction::ALL {
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().delta(), -direction.delta());
+            assert_eq!(direction.delta().chebyshev(Point::ORIGIN), 1);
+        }
+        assert_eq!(Direction::ALL.iter().filter(|d| d.is_diagonal()).count(), 4);
+        assert_eq!(SW.to_string(), "SW");
+    }
+}
@@ -0,0 +1,358 @@
+//! Graph searches over a neighbour closure, so the graph never has to be built: nodes can be grid
+//! points, node names (as on 2023 day 8) or any other state that is `Clone + Eq + Hash`.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The number of steps to every node reachable from `start`, `start` itself included at 0
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// Every node reachable from `start`, `start` included
+pub fn flood_fill<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+    seen
+}
+
+/// Groups `nodes` by what they can reach, in the order their first node appears. Edges are
+/// assumed to go both ways.
+pub fn connected_components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Vec<HashSet<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut components: Vec<HashSet<N>> = Vec::new();
+    for node in nodes {
+        if !components.iter().any(|component| component.contains(&node)) {
+            components.push(flood_fill(node, &mut neighbours));
+        }
+    }
+    components
+}
+
+/// The cheapest path from `start` to a node for which `is_goal` holds, with its cost. `neighbours`
+/// gives every next node with the cost of stepping there.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but searching towards the goal first. `heuristic` estimates the remaining
+/// cost and must never overestimate it, or the path found may not be the cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // nodes are referred to by their index in `visited`: (node, cheapest cost, previous node)
+    let mut visited: Vec<(N, C, Option<usize>)> = vec![(start.clone(), C::default(), None)];
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        if cost > visited[index].1 {
+            // a cheaper way here was found after this one was queued
+            continue;
+        }
+        if is_goal(&visited[index].0) {
+            let mut path = vec![visited[index].0.clone()];
+            let mut previous = visited[index].2;
+            while let Some(i) = previous {
+                path.push(visited[i].0.clone());
+                previous = visited[i].2;
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+        let node = visited[index].0.clone();
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            let next_index = match indices.get(&next) {
+                Some(&i) if visited[i].1 <= next_cost => continue,
+                Some(&i) => {
+                    visited[i] = (next.clone(), next_cost, Some(index));
+                    i
+                }
+                None => {
+                    visited.push((next.clone(), next_cost, Some(index)));
+                    indices.insert(next.clone(), visited.len() - 1);
+                    visited.len() - 1
+                }
+            };
+            queue.push(Reverse((next_cost + heuristic(&next), next_cost, next_index)));
+        }
+    }
+    None
+}
+
+/// Orders `nodes` and everything reachable from them so every node comes before its neighbours.
+/// None if there is a cycle.
+pub fn topological_sort<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    // every node once, in the order it was found, with its edges as indices into `found`
+    let mut found: Vec<N> = Vec::new();
+    let mut indices: HashMap<N, usize> = HashMap::new();
+    for node in nodes {
+        if !indices.contains_key(&node) {
+            indices.insert(node.clone(), found.len());
+            found.push(node);
+        }
+    }
+    let mut edges: Vec<Vec<usize>> = Vec::new();
+    while edges.len() < found.len() {
+        let node = found[edges.len()].clone();
+        let targets = neighbours(&node)
+            .into_iter()
+            .map(|next| {
+                *indices.entry(next.clone()).or_insert_with(|| {
+                    found.push(next);
+                    found.len() - 1
+                })
+            })
+            .collect();
+        edges.push(targets);
+    }
+
+    let mut incoming = vec![0; found.len()];
+    for &target in edges.iter().flatten() {
+        incoming[target] += 1;
+    }
+    let mut ready: VecDeque<usize> = (0..found.len()).filter(|&i| incoming[i] == 0).collect();
+    let mut order = Vec::with_capacity(found.len());
+    while let Some(i) = ready.pop_front() {
+        order.push(found[i].clone());
+        for &target in &edges[i] {
+            incoming[target] -= 1;
+            if incoming[target] == 0 {
+                ready.push_back(target);
+            }
+        }
+    }
+    (order.len() == found.len()).then_some(order)
+}
+
+/// Where repeatedly applying a function starts going round in circles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps from the start to the first state on the cycle
+    pub start: usize,
+    /// Steps to go round the cycle once
+    pub length: usize,
+}
+
+/// Finds the cycle of `start`, `successor(start)`, ... with Brent's algorithm, which calls
+/// `successor` less often than [`floyd`]. Loops forever if the states never repeat.
+pub fn brent<S: Clone + Eq>(start: S, mut successor: impl FnMut(&S) -> S) -> Cycle {
+    // the tortoise waits at powers of two for the hare to come round
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = successor(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = successor(&hare);
+        length += 1;
+    }
+
+    Cycle { start: cycle_start(start, length, successor), length }
+}
+
+/// Finds the same cycle as [`brent`] with Floyd's tortoise and hare
+pub fn floyd<S: Clone + Eq>(start: S, mut successor: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = successor(&start);
+    let mut hare = successor(&tortoise);
+    while tortoise != hare {
+        tortoise = successor(&tortoise);
+        hare = successor(&hare);
+        hare = successor(&hare);
+    }
+    // the hare is now a multiple of the cycle length ahead
+    let mut length = 1;
+    let mut lap = successor(&tortoise);
+    while lap != tortoise {
+        lap = successor(&lap);
+        length += 1;
+    }
+
+    Cycle { start: cycle_start(start, length, successor), length }
+}
+
+// with one state `length` steps ahead of the other, they first meet at the start of the cycle
+fn cycle_start<S: Clone + Eq>(
+    start: S,
+    length: usize,
+    mut successor: impl FnMut(&S) -> S,
+) -> usize {
+    let mut ahead = start.clone();
+    for _ in 0..length {
+        ahead = successor(&ahead);
+    }
+    let mut behind = start;
+    let mut steps = 0;
+    while behind != ahead {
+        behind = successor(&behind);
+        ahead = successor(&ahead);
+        steps += 1;
+    }
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{geometry::Point, grid::Grid};
+
+    // # are walls
+    const MAZE: &str = "..#..\n.##.#\n.....\n###.#\n.....";
+
+    #[test]
+    fn test_bfs_and_flood_fill() {
+        let maze = Grid::parse(MAZE, |c| Ok::<_, String>(c == '#')).unwrap();
+        let open = |&p: &Point| maze.neighbours4(p).filter(|&n| !maze[n]).collect::<Vec<_>>();
+        let distances = bfs(Point::new(0, 0), open);
+        assert_eq!(distances[&Point::new(0, 0)], 0);
+        assert_eq!(distances[&Point::new(3, 0)], 7);
+        assert_eq!(distances[&Point::new(0, 4)], 10);
+        assert_eq!(distances.len(), 17);
+
+        let filled = flood_fill(Point::new(4, 4), open);
+        assert_eq!(filled.len(), 17);
+        assert!(!filled.contains(&Point::new(2, 0)));
+        assert_eq!(flood_fill(Point::new(2, 0), |_: &Point| None).len(), 1);
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let network: HashMap<&str, Vec<(&str, u32)>> = HashMap::from([
+            ("AAA", vec![("BBB", 1), ("CCC", 5)]),
+            ("BBB", vec![("CCC", 2), ("DDD", 7)]),
+            ("CCC", vec![("DDD", 1)]),
+            ("DDD", vec![]),
+            ("EEE", vec![("AAA", 1)]),
+        ]);
+        let next = |node: &&str| network[node].clone();
+        let (path, cost) = dijkstra("AAA", next, |&node| node == "DDD").unwrap();
+        assert_eq!(path, vec!["AAA", "BBB", "CCC", "DDD"]);
+        assert_eq!(cost, 4);
+        assert_eq!(dijkstra("AAA", next, |&node| node == "AAA"), Some((vec!["AAA"], 0)));
+        assert_eq!(dijkstra("AAA", next, |&node| node == "EEE"), None);
+
+        let maze = Grid::parse(MAZE, |c| Ok::<_, String>(c == '#')).unwrap();
+        let goal = Point::new(4, 4);
+        let (path, cost) = astar(
+            Point::new(0, 0),
+            |&p| maze.neighbours4(p).filter(|&n| !maze[n]).map(|n| (n, 1)).collect::<Vec<_>>(),
+            |&p| p.manhattan(goal),
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), 9);
+        assert!(path.array_windows().all(|[a, b]| a.manhattan(*b) == 1));
+    }
+
+    #[test]
+    fn test_components_and_order() {
+        let edges = |&n: &u32| match n {
+            1 => vec![2],
+            2 => vec![1, 3],
+            3 => vec![2],
+            4 => vec![5],
+            5 => vec![4],
+            _ => vec![],
+        };
+        let components = connected_components(1..=6, edges);
+        assert_eq!(components.len(), 3);
+        assert_eq!(components[0], HashSet::from([1, 2, 3]));
+        assert_eq!(components[2], HashSet::from([6]));
+
+        // shirt before tie before jacket, trousers before shoes and jacket
+        let dressing = |&item: &&str| match item {
+            "shirt" => vec!["tie"],
+            "tie" => vec!["jacket"],
+            "trousers" => vec!["shoes", "jacket"],
+            _ => vec![],
+        };
+        let order = topological_sort(["trousers", "shirt"], dressing).unwrap();
+        let position = |item| order.iter().position(|&i| i == item).unwrap();
+        assert_eq!(order.len(), 5);
+        assert!(position("shirt") < position("tie"));
+        assert!(position("tie") < position("jacket"));
+        assert!(position("trousers") < position("jacket"));
+        assert!(position("trousers") < position("shoes"));
+        assert_eq!(topological_sort([1], edges), None);
+        assert_eq!(topological_sort([6], edges), Some(vec![6]));
+    }
+
+    #[test]
+    fn test_cycles() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 2
+        let next = |&n: &u32| if n == 5 { 2 } else { n + 1 };
+        assert_eq!(brent(0, next), Cycle { start: 2, length: 4 });
+        assert_eq!(floyd(0, next), Cycle { start: 2, length: 4 });
+        assert_eq!(brent(3, next), Cycle { start: 0, length: 4 });
+        // a fixed point is a cycle of length 1
+        assert_eq!(brent(7, |_| 7), Cycle { start: 0, length: 1 });
+        assert_eq!(floyd(7, |_| 7), Cycle { start: 0, length: 1 });
+        let squares = |&n: &u64| (n * n + 1) % 255;
+        assert_eq!(brent(3, squares), floyd(3, squares));
+    }
+}
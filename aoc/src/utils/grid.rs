@@ -0,0 +1,276 @@
+//! Rectangular grids of cells, as in most 2D puzzles. Cells are addressed by [`Point`], with `x`
+//! the column and `y` the row, both counted from the top left.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use super::{
+    geometry::{Direction, Point},
+    ParseError,
+};
+
+/// A `width` x `height` grid of cells, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Parse one cell per character, one row per line. `cell` rejects a character by returning an
+    /// error message, which is reported pointing at that character.
+    pub fn parse<F, E>(input: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Into<String>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            let before = cells.len();
+            for (i, c) in line.char_indices() {
+                let span = &line[i..i + c.len_utf8()];
+                cells.push(cell(c).map_err(|e| ParseError::at(input, span, e))?);
+            }
+            let row_width = cells.len() - before;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseError::at(input, line, "Row differs in length from the first"));
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid { width, height, cells }),
+            _ => Err(ParseError::new("Expected a grid, the input is empty")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    /// The cell at `point`, None if it is outside the grid
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every point of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its point, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point, row by row, whose cell matches
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(point, _)| point)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "Row {y} is outside the {}x{} grid", self.width, self.height);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "Column {x} is outside the {}x{} grid", self.width, self.height);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The point one step from `point` towards `direction`, None if that leaves the grid
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        Some(point.step(direction)).filter(|&next| self.contains(next))
+    }
+
+    /// The up to 4 points next to `point` on the grid, clockwise starting north
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &Direction::CARDINAL)
+    }
+
+    /// Like [`neighbours4`](Self::neighbours4), but including the diagonals
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbours(point, &Direction::ALL)
+    }
+
+    fn neighbours(
+        &self,
+        point: Point,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Point> + '_ {
+        directions.iter().filter_map(move |&direction| self.step(point, direction))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Mirror along the diagonal from the top left, so rows become columns
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flat_map(|column| column.cloned()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flat_map(|column| column.rev().cloned()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width).rev().flat_map(|x| self.column(x).cloned()).collect();
+        Grid { width: self.height, height: self.width, cells }
+    }
+}
+
+impl Grid<char> {
+    /// A grid of the characters in `input`
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Self::parse(input, Ok::<char, String>)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(point)
+            .unwrap_or_else(|| panic!("Point {point} is outside the {width}x{height} grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("Point {point} is outside the {width}x{height} grid"))
+    }
+}
+
+/// One line per row, without a trailing newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#..\n.#.\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse_chars(INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 1)], '#');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.to_string(), "#..\n.#.");
+
+        let error = Grid::parse(INPUT, |c| match c {
+            '#' => Ok(true),
+            other => Err(format!("Unknown cell {other}")),
+        })
+        .unwrap_err();
+        assert_eq!(error.position(), Some((1, 2)));
+        let error = Grid::parse_chars("#..\n.#\n").unwrap_err();
+        assert_eq!(error.position(), Some((2, 1)));
+        assert!(Grid::parse_chars("\n").is_err());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid = Grid::parse(INPUT, |c| Ok::<_, String>(c == '#')).unwrap();
+        grid[Point::new(2, 0)] = true;
+        assert_eq!(grid.row(0), &[true, false, true]);
+        assert_eq!(grid.column(1).collect::<Vec<_>>(), vec![&false, &true]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.find(|&cell| cell), Some(Point::new(0, 0)));
+        assert_eq!(grid.iter().filter(|(_, &cell)| cell).count(), 3);
+        assert_eq!(grid.points().last(), Some(Point::new(2, 1)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours4(Point::new(2, 2)).count(), 2);
+        assert_eq!(grid.neighbours4(Point::new(5, 5)).count(), 0);
+
+        assert_eq!(grid.step(Point::new(1, 0), Direction::S), Some(Point::new(1, 1)));
+        assert_eq!(grid.step(Point::new(1, 0), Direction::N), None);
+        assert_eq!(grid.step(Point::new(2, 1), Direction::E), None);
+        assert_eq!(grid.step(Point::new(0, 2), Direction::SW), None);
+        assert_eq!(grid.step(Point::new(2, 2), Direction::NW), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = Grid::parse_chars("abc\ndef").unwrap();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+}
@@ -0,0 +1,179 @@
+//! Loading puzzle inputs. The runner reads every input through here, and days split the content
+//! into records with [`records`].
+
+use std::{
+    error::Error,
+    fmt, fs,
+    io::{self, Read},
+    path::Path,
+    str::FromStr,
+};
+
+/// How errors refer to input read from stdin
+pub const STDIN_SOURCE: &str = "<stdin>";
+
+/// Reading or parsing an input failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlurpError {
+    /// Path of the input, `<stdin>` for stdin
+    source: String,
+    /// 1-based line of the line or record that failed to parse, None for I/O errors
+    line: Option<usize>,
+    msg: String,
+}
+
+impl SlurpError {
+    fn io(path: &Path, e: io::Error) -> Self {
+        SlurpError { source: path.display().to_string(), line: None, msg: e.to_string() }
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+}
+
+impl fmt::Display for SlurpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{line}: {}", self.source, self.msg),
+            None => write!(f, "Could not read input {}: {}", self.source, self.msg),
+        }
+    }
+}
+
+impl Error for SlurpError {}
+
+pub fn slurp_bytes<P>(path: P) -> Result<Vec<u8>, SlurpError>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    fs::read(path).map_err(|e| SlurpError::io(path, e))
+}
+
+pub fn slurp_string<P>(path: P) -> Result<String, SlurpError>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|e| SlurpError::io(path, e))
+}
+
+pub fn slurp_stdin() -> Result<String, SlurpError> {
+    let mut content = String::new();
+    io::stdin().read_to_string(&mut content).map_err(|e| SlurpError {
+        source: STDIN_SOURCE.to_string(),
+        line: None,
+        msg: e.to_string(),
+    })?;
+    Ok(content)
+}
+
+/// Slurp file will try to parse every line into `T` as long as T implements FromStr
+pub fn slurp_file<P, T>(path: P) -> Result<Vec<T>, SlurpError>
+where
+    P: AsRef<Path>,
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Display,
+{
+    let path = path.as_ref();
+    let content = slurp_string(path)?;
+    content.lines().map(|line| parse(path, &content, line)).collect()
+}
+
+/// Like [`slurp_file`], but for records separated by blank lines, e.g. the maps of 2023 day 5
+pub fn slurp_records<P, T>(path: P) -> Result<Vec<T>, SlurpError>
+where
+    P: AsRef<Path>,
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Display,
+{
+    let path = path.as_ref();
+    let content = slurp_string(path)?;
+    records(&content).into_iter().map(|record| parse(path, &content, record)).collect()
+}
+
+// `text` has to be a slice of `content`, errors point at its first line
+fn parse<T>(path: &Path, content: &str, text: &str) -> Result<T, SlurpError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Display,
+{
+    text.parse().map_err(|e: T::Err| {
+        let offset = text.as_ptr() as usize - content.as_ptr() as usize;
+        SlurpError {
+            source: path.display().to_string(),
+            line: Some(content[..offset].matches('\n').count() + 1),
+            msg: e.to_string(),
+        }
+    })
+}
+
+/// Split `input` into records separated by one or more blank lines. Records are slices of `input`
+/// without their trailing newline, so they can be pointed at with
+/// [`ParseError::at`](super::ParseError::at).
+pub fn records(input: &str) -> Vec<&str> {
+    let mut records = Vec::new();
+    // start and end of the record being collected
+    let mut current: Option<(usize, usize)> = None;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some((start, end)) = current.take() {
+                records.push(&input[start..end]);
+            }
+        } else {
+            let start = current.map_or(offset, |(start, _)| start);
+            current = Some((start, offset + content.len()));
+        }
+        offset += line.len();
+    }
+    if let Some((start, end)) = current {
+        records.push(&input[start..end]);
+    }
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_records() {
+        let input =
+            "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\r\n \r\n\n\nsoil-to-fertilizer map:\n";
+        assert_eq!(
+            records(input),
+            vec!["seeds: 79 14", "seed-to-soil map:\n50 98 2", "soil-to-fertilizer map:"]
+        );
+        assert!(records("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_slurp() {
+        let dir = env::temp_dir().join(format!("aoc-slurp-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+        fs::write(&path, "1\n2\n\n3\nx\n").unwrap();
+
+        let error = slurp_file::<_, u32>(&path).unwrap_err();
+        assert_eq!(error.line(), Some(3));
+        assert!(error.to_string().starts_with(&format!("{}:3: ", path.display())));
+
+        fs::write(&path, "1\n\n\n2\n\n3\nx\n").unwrap();
+        let error = slurp_records::<_, u32>(&path).unwrap_err();
+        assert_eq!(error.line(), Some(6));
+        fs::write(&path, "1\n\n\n2\n").unwrap();
+        assert_eq!(slurp_records::<_, u32>(&path).unwrap(), vec![1, 2]);
+        assert_eq!(slurp_bytes(&path).unwrap(), b"1\n\n\n2\n");
+
+        let missing = dir.join("missing.txt");
+        let error = slurp_bytes(&missing).unwrap_err();
+        assert_eq!(error.line(), None);
+        assert!(error.to_string().contains(&missing.display().to_string()));
+        assert!(slurp_file::<_, u32>(&missing).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,350 @@
+//! Ranges of integers handled as a whole instead of number by number: [`Interval`]s, sets of them
+//! ([`RangeSet`]) and maps that shift parts of the number line ([`RangeMap`]), as the conversion
+//! tables of 2023 day 5 do.
+
+use std::{fmt, ops::Range};
+
+/// The half-open range `start..end`, empty if `end <= start`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Self {
+        Interval { start, end }
+    }
+
+    /// The `len` numbers from `start` on, None if the end doesn't fit an `i64`
+    pub const fn with_len(start: i64, len: i64) -> Option<Self> {
+        match start.checked_add(len) {
+            Some(end) => Some(Interval { start, end }),
+            None => None,
+        }
+    }
+
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The numbers in both, None if there are none
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let overlap = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(range: Range<i64>) -> Self {
+        Interval::new(range.start, range.end)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers, stored as sorted intervals that neither overlap nor touch
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        RangeSet::default()
+    }
+
+    /// Adds every number of `interval`, merging it with the intervals it overlaps or touches
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+        // the intervals before `first` end before it, those from `last` on start after it
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many numbers are in the set
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.intervals.partition_point(|interval| interval.end <= value);
+        self.intervals.get(i).is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        for &interval in &other.intervals {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut intersection = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intersection.extend(a.intersection(b));
+            // whichever ends first can't overlap anything further in the other set
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        RangeSet { intervals: intersection }
+    }
+
+    /// The numbers of `self` that are not in `other`
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut difference = Vec::new();
+        let mut j = 0;
+        for &interval in &self.intervals {
+            let mut rest = interval;
+            // skip what ends before this interval, it is also before the following ones
+            while other.intervals.get(j).is_some_and(|b| b.end <= rest.start) {
+                j += 1;
+            }
+            for b in &other.intervals[j..] {
+                if b.start >= rest.end {
+                    break;
+                }
+                if b.start > rest.start {
+                    difference.push(Interval::new(rest.start, b.start));
+                }
+                rest.start = rest.start.max(b.end);
+            }
+            if !rest.is_empty() {
+                difference.push(rest);
+            }
+        }
+        RangeSet { intervals: difference }
+    }
+}
+
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = RangeSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(interval: Interval) -> Self {
+        RangeSet::from_iter([interval])
+    }
+}
+
+/// Maps every number to itself, except those in one of its source intervals, which are shifted by
+/// that interval's offset
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeMap {
+    /// (source, offset), sorted and not overlapping
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        RangeMap::default()
+    }
+
+    /// Shift the numbers of `source` by `offset`. Numbers an earlier insert already maps keep their
+    /// mapping, as the first matching line of a day 5 table wins.
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        for (gap, mapped) in self.segments(source) {
+            if mapped.is_none() {
+                self.pieces.push((gap, offset));
+            }
+        }
+        self.pieces.sort_by_key(|(source, _)| source.start);
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        let i = self.pieces.partition_point(|(source, _)| source.end <= value);
+        match self.pieces.get(i) {
+            Some((source, offset)) if source.contains(value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Where the numbers of `interval` end up
+    pub fn map_interval(&self, interval: Interval) -> RangeSet {
+        self.segments(interval)
+            .into_iter()
+            .map(|(part, offset)| part.shift(offset.unwrap_or(0)))
+            .collect()
+    }
+
+    /// Where the numbers of `set` end up
+    pub fn map_set(&self, set: &RangeSet) -> RangeSet {
+        let mut mapped = RangeSet::new();
+        for &interval in set.intervals() {
+            for &part in self.map_interval(interval).intervals() {
+                mapped.insert(part);
+            }
+        }
+        mapped
+    }
+
+    /// The map applying `self` and then `next`
+    pub fn then(&self, next: &RangeMap) -> RangeMap {
+        let mut composed = RangeMap::new();
+        for &(source, offset) in &self.pieces {
+            for (part, next_offset) in next.segments(source.shift(offset)) {
+                composed.insert(part.shift(-offset), offset + next_offset.unwrap_or(0));
+            }
+        }
+        // where `self` maps numbers to themselves, only `next` moves them
+        for &(source, next_offset) in &next.pieces {
+            composed.insert(source, next_offset);
+        }
+        composed
+    }
+
+    // `interval` cut at the boundaries of the pieces, each part with its offset, None outside them
+    fn segments(&self, interval: Interval) -> Vec<(Interval, Option<i64>)> {
+        let mut segments = Vec::new();
+        let mut start = interval.start;
+        let first = self.pieces.partition_point(|(source, _)| source.end <= interval.start);
+        for &(source, offset) in &self.pieces[first..] {
+            if source.start >= interval.end {
+                break;
+            }
+            if source.start > start {
+                segments.push((Interval::new(start, source.start), None));
+            }
+            let overlap = Interval::new(start.max(source.start), source.end.min(interval.end));
+            segments.push((overlap, Some(offset)));
+            start = overlap.end;
+        }
+        if start < interval.end {
+            segments.push((Interval::new(start, interval.end), None));
+        }
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[Range<i64>]) -> RangeSet {
+        intervals.iter().cloned().map(Interval::from).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(2, 5);
+        assert_eq!(a.len(), 3);
+        assert!(a.contains(2) && !a.contains(5));
+        assert_eq!(Interval::with_len(2, 3), Some(a));
+        assert_eq!(Interval::with_len(i64::MAX, 5), None);
+        assert_eq!(a.intersection(&Interval::new(4, 9)), Some(Interval::new(4, 5)));
+        assert_eq!(a.intersection(&Interval::new(5, 9)), None);
+        assert_eq!(a.shift(-3), Interval::new(-1, 2));
+        assert!(Interval::new(3, 3).is_empty());
+        assert_eq!(Interval::new(5, 2).len(), 0);
+        assert_eq!(a.to_string(), "2..5");
+    }
+
+    #[test]
+    fn test_range_set() {
+        let a = set(&[5..8, 0..2, 2..3, 10..12, 7..9]);
+        assert_eq!(a.intervals(), set(&[0..3, 5..9, 10..12]).intervals());
+        assert_eq!(a.len(), 9);
+        assert_eq!((a.min(), a.max()), (Some(0), Some(11)));
+        assert!(a.contains(8) && !a.contains(9) && !a.contains(-1) && !a.contains(12));
+        let mut bridged = a.clone();
+        bridged.insert(Interval::new(1, 11));
+        assert_eq!(bridged, RangeSet::from(Interval::new(0, 12)));
+        bridged.insert(Interval::new(20, 20));
+        assert_eq!(bridged, RangeSet::from(Interval::new(0, 12)));
+
+        let b = set(&[1..6, 8..11, 20..30]);
+        assert_eq!(a.union(&b), set(&[0..12, 20..30]));
+        assert_eq!(a.intersection(&b), set(&[1..3, 5..6, 8..9, 10..11]));
+        assert_eq!(a.difference(&b), set(&[0..1, 6..8, 11..12]));
+        assert_eq!(b.difference(&a), set(&[3..5, 9..10, 20..30]));
+        assert_eq!(a.difference(&a), RangeSet::new());
+        assert_eq!(a.difference(&RangeSet::new()), a);
+        assert!(a.intersection(&RangeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn test_range_map() {
+        // the seed-to-soil map of the day 5 example: 50 98 2, 52 50 48
+        let mut seed_to_soil = RangeMap::new();
+        seed_to_soil.insert(Interval::with_len(98, 2).unwrap(), 50 - 98);
+        seed_to_soil.insert(Interval::with_len(50, 48).unwrap(), 52 - 50);
+        assert_eq!(
+            [79, 14, 55, 13, 98, 99, 100].map(|n| seed_to_soil.get(n)),
+            [81, 14, 57, 13, 50, 51, 100]
+        );
+        assert_eq!(
+            seed_to_soil.map_interval(Interval::new(45, 100)),
+            RangeSet::from(Interval::new(45, 100))
+        );
+        assert_eq!(seed_to_soil.map_set(&set(&[79..93, 55..68])), set(&[57..70, 81..95]));
+
+        // an overlapping insert only maps what isn't mapped yet
+        let mut first_wins = RangeMap::new();
+        first_wins.insert(Interval::new(0, 10), 100);
+        first_wins.insert(Interval::new(5, 15), -5);
+        assert_eq!([0, 9, 10, 14, 15].map(|n| first_wins.get(n)), [100, 109, 5, 9, 15]);
+
+        let composed = seed_to_soil.then(&first_wins);
+        let composed_back = first_wins.then(&seed_to_soil);
+        for n in -5..120 {
+            assert_eq!(composed.get(n), first_wins.get(seed_to_soil.get(n)), "{n}");
+            assert_eq!(composed_back.get(n), seed_to_soil.get(first_wins.get(n)), "{n}");
+        }
+        assert_eq!(RangeMap::new().then(&first_wins), first_wins);
+    }
+}
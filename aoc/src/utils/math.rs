@@ -0,0 +1,148 @@
+//! Number theory for puzzles about things lining up: gcd/lcm, modular inverses and the Chinese
+//! Remainder Theorem. Anything that could overflow returns None instead.
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Least common multiple, None if it doesn't fit a u64
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The gcd of all `numbers`, 0 for none
+pub fn gcd_all(numbers: &[u64]) -> u64 {
+    numbers.iter().fold(0, |acc, &n| gcd(acc, n))
+}
+
+/// The lcm of all `numbers`, 1 for none
+pub fn lcm_all(numbers: &[u64]) -> Option<u64> {
+    numbers.iter().try_fold(1, |acc, &n| lcm(acc, n))
+}
+
+/// `(g, x, y)` with `g = gcd(a, b) = a * x + b * y`, `g` never negative
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_wide(a.into(), b.into());
+    // only gcd(i64::MIN, 0) and gcd(i64::MIN, i64::MIN) don't fit, as 2^63
+    let narrow = |n: i128| i64::try_from(n).expect("Extended gcd doesn't fit an i64");
+    (narrow(g), narrow(x), narrow(y))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, None if `a` and `modulus` share a factor
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd_wide(a.into(), modulus.into());
+    (g == 1).then(|| x.rem_euclid(modulus.into()) as i64)
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, returning the smallest
+/// non-negative `x` and the lcm of the moduli, which all solutions are `x` plus a multiple of. The
+/// moduli don't have to be coprime. None if the congruences contradict each other, a modulus is
+/// not positive or the lcm doesn't fit an i64.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x: i128 = 0;
+    let mut modulus: i128 = 1;
+    for &(residue, next_modulus) in congruences {
+        if next_modulus <= 0 {
+            return None;
+        }
+        let next_modulus = i128::from(next_modulus);
+        let residue = i128::from(residue).rem_euclid(next_modulus);
+        // x + modulus * k ≡ residue (mod next_modulus) has a solution iff g divides the difference
+        let (g, p, _) = extended_gcd_wide(modulus, next_modulus);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let step = next_modulus / g;
+        let k = (difference / g % step * p).rem_euclid(step);
+        let combined = modulus * step;
+        if combined > i64::MAX.into() {
+            return None;
+        }
+        x = (x + modulus * k).rem_euclid(combined);
+        modulus = combined;
+    }
+    Some((x as i64, modulus as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(lcm(u64::MAX, u64::MAX), Some(u64::MAX));
+        assert_eq!(gcd_all(&[12, 18, 27]), 3);
+        assert_eq!(gcd_all(&[]), 0);
+        assert_eq!(lcm_all(&[2, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(&[]), Some(1));
+        assert_eq!(lcm_all(&[1 << 40, 3 << 30, 5 << 20, 7]), Some(105 << 40));
+        assert_eq!(lcm_all(&[3, 1 << 40, u64::MAX / 2]), None);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (-240, 46), (46, -240), (0, 7), (7, 0), (0, 0), (1, 1)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+        assert_eq!(mod_inverse(5, 0), None);
+        let big = i64::MAX;
+        assert_eq!(mod_inverse(big - 1, big), Some(big - 1));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non-coprime moduli
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[(0, 6), (0, 4)]), Some((0, 12)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 0)]), None);
+        assert_eq!(crt(&[(0, i64::MAX), (1, 2)]), None);
+        let big = (1 << 31) - 1;
+        let (x, modulus) = crt(&[(1, big), (2, big + 2)]).unwrap();
+        assert_eq!(modulus, big * (big + 2));
+        assert_eq!((x % big, x % (big + 2)), (1, 2));
+    }
+}
@@ -0,0 +1,167 @@
+use std::{error::Error, fmt, str::FromStr};
+
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+
+pub use input::{records, slurp_bytes, slurp_file, slurp_records, SlurpError};
+
+/// An error in a puzzle input, pointing at the offending text. Displayed with the line it was
+/// found on and a caret underneath:
+///
+/// ```text
+/// Expected a number, found "x"
+///  --> inputs/2023/day_2/test1.txt:3:9
+///   |
+/// 3 | Game 3: x green
+///   |         ^
+/// ```
+///
+/// The alternate form (`{:#}`) fits on one line for tables: `Expected a number, found "x" at
+/// inputs/2023/day_2/test1.txt:3:9`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    msg: String,
+    location: Option<Location>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Location {
+    file: Option<String>,
+    /// 1-based
+    line: usize,
+    /// 1-based, in characters
+    column: usize,
+    /// The whole line the error is on
+    text: String,
+    /// Number of characters to underline
+    len: usize,
+}
+
+impl ParseError {
+    /// An error without a location, for problems with the input as a whole
+    pub fn new(msg: impl Into<String>) -> Self {
+        Self { msg: msg.into(), location: None }
+    }
+
+    /// An error pointing at `span`, which has to be a slice of `input`
+    pub fn at(input: &str, span: &str, msg: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        if offset > input.len() {
+            return Self::new(msg);
+        }
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..].find('\n').map_or(input.len(), |i| offset + i);
+        let text = input[line_start..line_end].trim_end_matches('\r');
+        let column = input[line_start..offset].chars().count() + 1;
+        let rest = text.chars().count().saturating_sub(column - 1);
+        let location = Location {
+            file: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column,
+            text: text.to_string(),
+            len: span.chars().count().clamp(1, rest.max(1)),
+        };
+        Self { msg: msg.into(), location: Some(location) }
+    }
+
+    /// Name the file the input was read from
+    pub fn with_file(mut self, file: &str) -> Self {
+        if let Some(location) = &mut self.location {
+            location.file = Some(file.to_string());
+        }
+        self
+    }
+
+    pub fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    /// 1-based line and column of the error, if it has a location
+    pub fn position(&self) -> Option<(usize, usize)> {
+        self.location.as_ref().map(|location| (location.line, location.column))
+    }
+}
+
+impl Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.msg)?;
+        let Some(location) = &self.location else {
+            return Ok(());
+        };
+        if f.alternate() {
+            return match &location.file {
+                Some(file) => write!(f, " at {file}:{}:{}", location.line, location.column),
+                None => write!(f, " at line {}:{}", location.line, location.column),
+            };
+        }
+        let gutter = " ".repeat(location.line.to_string().len());
+        match &location.file {
+            Some(file) => write!(f, "\n{gutter}--> {file}:{}:{}", location.line, location.column)?,
+            None => write!(f, "\n{gutter}--> line {}:{}", location.line, location.column)?,
+        }
+        write!(f, "\n{gutter} |\n{} | {}", location.line, location.text)?;
+        write!(f, "\n{gutter} | {}{}", " ".repeat(location.column - 1), "^".repeat(location.len))
+    }
+}
+
+/// Parse `span`, a slice of `input`, pointing at it if it isn't a valid `T`
+pub fn parse_at<T>(input: &str, span: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    <T as FromStr>::Err: fmt::Display,
+{
+    span.trim()
+        .parse()
+        .map_err(|e| ParseError::at(input, span, format!("Could not parse {span:?}: {e}")))
+}
+
+/// Split `span`, a slice of `input`, at the first `delimiter`, pointing at it if there is none
+pub fn split_once_at<'a>(
+    input: &str,
+    span: &'a str,
+    delimiter: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    span.split_once(delimiter)
+        .ok_or_else(|| ParseError::at(input, span, format!("Expected {delimiter:?}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "Game 1: 3 blue\nGame 2: x green\n";
+        let span = &input[23..24];
+        let error = ParseError::at(input, span, "Expected a number").with_file("test1.txt");
+        assert_eq!(error.position(), Some((2, 9)));
+        assert_eq!(format!("{error:#}"), "Expected a number at test1.txt:2:9");
+        assert_eq!(
+            error.to_string(),
+            "Expected a number\n --> test1.txt:2:9\n  |\n2 | Game 2: x green\n  |         ^"
+        );
+
+        let error = parse_at::<u32>(input, &input[15..21]).unwrap_err();
+        assert_eq!(error.position(), Some((2, 1)));
+        assert!(error.to_string().ends_with("\n  | ^^^^^^"));
+
+        // spans from elsewhere can't be located
+        assert_eq!(ParseError::at(input, "x", "Unknown").position(), None);
+        assert_eq!(ParseError::at(input, "x", "Unknown").to_string(), "Unknown");
+    }
+
+    #[test]
+    fn test_split_once_at() {
+        let input = "a -> b\nc";
+        assert_eq!(split_once_at(input, &input[..6], " -> ").unwrap(), ("a", "b"));
+        let error = split_once_at(input, &input[7..], " -> ").unwrap_err();
+        assert_eq!(error.position(), Some((2, 1)));
+        assert_eq!(error.msg(), "Expected \" -> \"");
+    }
+}
@@ -0,0 +1,147 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+type DynError = Box<dyn Error>;
+
+pub const FILE_NAME: &str = "aoc.toml";
+const DEFAULT_SERVER: &str = "https://adventofcode.com";
+
+/// Workspace layout read from `aoc.toml`, with every path resolved against the file's directory.
+/// `inputs` and `answers` may contain `{year}`, use the methods to get the paths for a year.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Year used when none is given
+    pub year: u32,
+    /// Holds the `day0.rs` template and a `y<year>` directory of solutions per year
+    pub commands: PathBuf,
+    pub inputs: PathBuf,
+    pub outputs: PathBuf,
+    pub answers: PathBuf,
+    #[serde(default = "default_server")]
+    pub server: String,
+    /// Directory holding `aoc.toml`
+    #[serde(skip)]
+    pub root: PathBuf,
+}
+
+fn default_server() -> String {
+    DEFAULT_SERVER.to_string()
+}
+
+impl Config {
+    /// Find `aoc.toml` in the current directory or one of its parents, falling back to the
+    /// ancestors of `fallback` so the tools still work when run from outside the workspace
+    pub fn discover(fallback: &Path) -> Result<Self, DynError> {
+        let cwd = env::current_dir()?;
+        let path = find(&cwd).or_else(|| find(fallback)).ok_or_else(|| {
+            format!(
+                "No {FILE_NAME} in {} or {}, or their parents",
+                cwd.display(),
+                fallback.display()
+            )
+        })?;
+        Self::load(&path)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, DynError> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {e}", path.display()))?;
+        let root = path.parent().expect("A config file lives in a directory");
+        Self::parse(&content, root).map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+
+    pub fn parse(content: &str, root: &Path) -> Result<Self, DynError> {
+        let mut config: Config = toml::from_str(content)?;
+        for path in
+            [&mut config.commands, &mut config.inputs, &mut config.outputs, &mut config.answers]
+        {
+            *path = root.join(&*path);
+        }
+        config.server = config.server.trim_end_matches('/').to_string();
+        config.root = root.to_path_buf();
+        Ok(config)
+    }
+
+    pub fn commands_dir(&self, year: u32) -> PathBuf {
+        self.commands.join(format!("y{year}"))
+    }
+
+    pub fn inputs_dir(&self, year: u32, day: u32) -> PathBuf {
+        for_year(&self.inputs, year).join(format!("day_{day}"))
+    }
+
+    pub fn answers_path(&self, year: u32) -> PathBuf {
+        for_year(&self.answers, year)
+    }
+
+    /// Unconfirmed answers taken from the puzzle text, kept next to the answers file, e.g.
+    /// `2023.candidates.toml`. Nothing checks solutions against them.
+    pub fn candidates_path(&self, year: u32) -> PathBuf {
+        self.answers_path(year).with_extension("candidates.toml")
+    }
+
+    /// Years with a solutions directory, in order
+    pub fn years(&self) -> Vec<u32> {
+        let mut years: Vec<u32> = fs::read_dir(&self.commands)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let year = entry.file_name().to_str()?.strip_prefix('y')?.parse().ok()?;
+                entry.path().is_dir().then_some(year)
+            })
+            .collect();
+        years.sort();
+        years
+    }
+}
+
+fn for_year(path: &Path, year: u32) -> PathBuf {
+    PathBuf::from(path.to_string_lossy().replace("{year}", &year.to_string()))
+}
+
+fn find(start: &Path) -> Option<PathBuf> {
+    start.ancestors().map(|dir| dir.join(FILE_NAME)).find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let content = "
+        year = 2024
+        commands = \"aoc/src/commands\"
+        inputs = \"aoc/inputs/{year}\"
+        outputs = \"out\"
+        answers = \"/abs/answers.toml\"
+        ";
+        let config = Config::parse(content, Path::new("/work")).unwrap();
+        assert_eq!(config.year, 2024);
+        assert_eq!(config.commands_dir(2015), Path::new("/work/aoc/src/commands/y2015"));
+        assert_eq!(config.inputs_dir(2015, 3), Path::new("/work/aoc/inputs/2015/day_3"));
+        assert_eq!(config.outputs, Path::new("/work/out"));
+        assert_eq!(config.answers_path(2015), Path::new("/abs/answers.toml"));
+        assert_eq!(config.candidates_path(2015), Path::new("/abs/answers.candidates.toml"));
+        assert_eq!(config.server, "https://adventofcode.com");
+        assert_eq!(config.root, Path::new("/work"));
+
+        assert!(Config::parse("year = 2024", Path::new("/work")).is_err());
+        assert!(Config::parse(&format!("{content}\ntypo = 1"), Path::new("/work")).is_err());
+    }
+
+    #[test]
+    fn test_checked_in_config() {
+        let config = Config::discover(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+        assert!(config.root.join("Cargo.toml").is_file());
+        assert!(config.commands.join("day0.rs").is_file());
+        assert!(config.years().contains(&config.year));
+        assert!(config.answers_path(config.year).is_file());
+    }
+}
@@ -0,0 +1,107 @@
+use std::fs;
+use std::path::Path;
+
+use toml_edit::{value, Document, Item, Table};
+
+use crate::DynError;
+
+/// Store `answer` under `[day_N.part]` for `input`, keeping the rest of the file as it is.
+/// Returns the answer that was recorded before, if it differed.
+pub fn record_answer(
+    path: &Path,
+    day: u32,
+    part: &str,
+    input: &str,
+    answer: &str,
+) -> Result<Option<String>, DynError> {
+    let mut doc = load(path)?;
+    let part_table = part_table(&mut doc, day, part)?;
+    let previous = part_table.get(input).and_then(|item| item.as_str()).map(str::to_string);
+    if previous.as_deref() == Some(answer) {
+        return Ok(None);
+    }
+    part_table[input] = value(answer);
+    fs::write(path, doc.to_string())?;
+    Ok(previous)
+}
+
+/// Store an answer that has not been confirmed yet in the separate `candidates` file, laid out
+/// like the answers file. Nothing is stored if `answers` or `candidates` already have an answer
+/// for `input`. Returns whether the candidate was added.
+pub fn record_candidate(
+    answers: &Path,
+    candidates: &Path,
+    day: u32,
+    part: &str,
+    input: &str,
+    answer: &str,
+) -> Result<bool, DynError> {
+    if part_table(&mut load(answers)?, day, part)?.contains_key(input) {
+        return Ok(false);
+    }
+    let mut doc = load(candidates)?;
+    let part_table = part_table(&mut doc, day, part)?;
+    if part_table.contains_key(input) {
+        return Ok(false);
+    }
+    part_table[input] = value(answer);
+    fs::write(candidates, doc.to_string())?;
+    Ok(true)
+}
+
+fn load(path: &Path) -> Result<Document, DynError> {
+    Ok(fs::read_to_string(path).unwrap_or_default().parse()?)
+}
+
+fn part_table<'a>(doc: &'a mut Document, day: u32, part: &str) -> Result<&'a mut Table, DynError> {
+    let day_key = format!("day_{day}");
+    // `[day_N]` itself is never written, only `[day_N.a]` and `[day_N.b]`
+    let day_table = doc.entry(&day_key).or_insert_with(|| {
+        let mut table = Table::new();
+        table.set_implicit(true);
+        Item::Table(table)
+    });
+    let part_table = day_table
+        .as_table_mut()
+        .ok_or(format!("{day_key} is not a table"))?
+        .entry(part)
+        .or_insert(Item::Table(Table::new()));
+    Ok(part_table.as_table_mut().ok_or(format!("{day_key}.{part} is not a table"))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_record_answer() {
+        let path = env::temp_dir().join(format!("xtask-answers-{}.toml", std::process::id()));
+        fs::write(&path, "# header\n\n[day_1.a]\nchallenge1 = \"54304\"\n").unwrap();
+
+        assert_eq!(record_answer(&path, 1, "a", "test1", "142").unwrap(), None);
+        assert_eq!(record_answer(&path, 12, "b", "challenge1", "7").unwrap(), None);
+        assert_eq!(record_answer(&path, 12, "b", "challenge1", "7").unwrap(), None);
+        assert_eq!(record_answer(&path, 1, "a", "challenge1", "1").unwrap(), Some("54304".into()));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# header\n\n[day_1.a]\nchallenge1 = \"1\"\ntest1 = \"142\"\n\n[day_12.b]\nchallenge1 = \"7\"\n"
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_record_candidate() {
+        let answers = env::temp_dir().join(format!("xtask-known-{}.toml", std::process::id()));
+        let candidates = answers.with_extension("candidates.toml");
+        fs::write(&answers, "[day_2.a]\ntest1 = \"8\"\n").unwrap();
+
+        assert!(!record_candidate(&answers, &candidates, 2, "a", "test1", "9").unwrap());
+        assert!(record_candidate(&answers, &candidates, 2, "b", "test1", "2286").unwrap());
+        assert!(!record_candidate(&answers, &candidates, 2, "b", "test1", "2287").unwrap());
+        assert_eq!(fs::read_to_string(&answers).unwrap(), "[day_2.a]\ntest1 = \"8\"\n");
+        assert_eq!(fs::read_to_string(&candidates).unwrap(), "[day_2.b]\ntest1 = \"2286\"\n");
+        fs::remove_file(answers).unwrap();
+        fs::remove_file(candidates).unwrap();
+    }
+}
@@ -0,0 +1,380 @@
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_config::Config;
+use clap::Parser;
+use serde::Deserialize;
+
+use crate::{CommandImpl, DynError};
+
+/// Benchmark every registered solution on its challenge input and write a report
+#[derive(Parser, Debug)]
+pub struct Bench {
+    /// Only benchmark this day
+    #[clap(long, short)]
+    day: Option<u32>,
+    /// [default: year in aoc.toml]
+    #[clap(long)]
+    year: Option<u32>,
+    /// Runs per solution, the report shows the median
+    #[clap(long, short, default_value_t = 10)]
+    iterations: usize,
+    /// Give up on a solution after this many seconds
+    #[clap(long, default_value_t = 60)]
+    timeout: u64,
+    /// Write the report here, as CSV if the name ends in .csv and markdown otherwise
+    #[clap(long, short)]
+    output: Option<PathBuf>,
+    /// Earlier report (markdown or CSV) to compare against
+    #[clap(long, short)]
+    baseline: Option<PathBuf>,
+    /// Flag solutions whose median got slower by more than this many percent
+    #[clap(long, default_value_t = 10.0)]
+    threshold: f64,
+}
+
+impl CommandImpl for Bench {
+    fn main(&self, config: &Config) -> Result<(), DynError> {
+        // read first so a bad baseline fails before the slow part
+        let baseline = match &self.baseline {
+            Some(path) => Some(read_report(path)?),
+            None => None,
+        };
+
+        let aoc = build_release(&config.root)?;
+        let year = self.year.unwrap_or(config.year).to_string();
+        let mut rows = Vec::new();
+        for listing in list_solutions(&aoc, &year)? {
+            if self.day.map_or(false, |day| day != listing.day) {
+                continue;
+            }
+            let row = bench_solution(&aoc, &year, listing, self.iterations, self.timeout);
+            println!("{}", row.summary());
+            rows.push(row);
+        }
+
+        if let Some(output) = &self.output {
+            fs::write(output, write_report(&rows, is_csv(output)))?;
+            println!("Wrote report to {}", output.display());
+        }
+        if let Some(baseline) = baseline {
+            let regressions = regressions(&baseline, &rows, self.threshold);
+            for regression in &regressions {
+                println!("Regression: {regression}");
+            }
+            if !regressions.is_empty() {
+                return Err(format!(
+                    "{} solutions got more than {}% slower",
+                    regressions.len(),
+                    self.threshold
+                )
+                .into());
+            }
+            println!("No regressions above {}%", self.threshold);
+        }
+        Ok(())
+    }
+}
+
+/// A solution variant as listed by `aoc list --format json`
+#[derive(Debug, Deserialize)]
+struct Listing {
+    day: u32,
+    part: String,
+    solution: String,
+    variant: String,
+}
+
+/// One line of the report
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub day: u32,
+    pub part: String,
+    pub variant: String,
+    pub median: Option<Duration>,
+    pub peak_rss_kb: Option<u64>,
+    /// `ok`, `timeout` or the error the solution failed with
+    pub status: String,
+}
+
+impl Row {
+    fn summary(&self) -> String {
+        let median = self.median.map_or("-".to_string(), |median| format!("{median:.3?}"));
+        format!("day{}{} [{}]: {median} ({})", self.day, self.part, self.variant, self.status)
+    }
+
+    fn key(&self) -> (u32, &str, &str) {
+        (self.day, &self.part, &self.variant)
+    }
+}
+
+fn build_release(root: &Path) -> Result<PathBuf, DynError> {
+    let status = Command::new(env!("CARGO"))
+        .current_dir(root)
+        .args(["build", "--release", "--bin", "aoc"])
+        .status()?;
+    if !status.success() {
+        return Err("Release build failed".into());
+    }
+    let target_dir =
+        env::var("CARGO_TARGET_DIR").map_or_else(|_| root.join("target"), PathBuf::from);
+    Ok(target_dir.join("release").join("aoc"))
+}
+
+fn list_solutions(aoc: &Path, year: &str) -> Result<Vec<Listing>, DynError> {
+    let output = Command::new(aoc)
+        .args(["--year", year, "list", "--format", "json"])
+        .stdin(Stdio::null())
+        .output()?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string().into());
+    }
+    let stdout = String::from_utf8(output.stdout)?;
+    let listings = stdout.lines().map(serde_json::from_str).collect::<Result<_, _>>()?;
+    Ok(listings)
+}
+
+// each solution runs in its own process, so the peak memory it reports is its own
+fn bench_solution(
+    aoc: &Path,
+    year: &str,
+    listing: Listing,
+    iterations: usize,
+    timeout: u64,
+) -> Row {
+    let mut row = Row {
+        day: listing.day,
+        part: listing.part,
+        variant: listing.variant,
+        median: None,
+        peak_rss_kb: None,
+        status: "ok".to_string(),
+    };
+    let iterations = iterations.to_string();
+    let args = ["--variant", &row.variant, "--input", "challenge1", "--bench", &iterations];
+    let output = Command::new(aoc)
+        .args(["--year", year, &listing.solution])
+        .args(args)
+        .args(["--format", "json", "--quiet"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .and_then(|child| wait_timeout(child, Duration::from_secs(timeout)));
+    let (stdout, stderr) = match output {
+        Ok(Some(output)) => output,
+        Ok(None) => {
+            row.status = "timeout".to_string();
+            return row;
+        }
+        Err(e) => {
+            row.status = format!("error: {e}");
+            return row;
+        }
+    };
+
+    let report: Option<serde_json::Value> =
+        stdout.lines().last().and_then(|line| serde_json::from_str(line).ok());
+    match report {
+        Some(report) if report["total"].is_object() => {
+            row.median = report["total"]["median_ns"].as_u64().map(Duration::from_nanos);
+            row.peak_rss_kb = report["peak_rss_kb"].as_u64();
+        }
+        _ => {
+            // errors can span several lines, e.g. a parse error showing the offending input
+            let message = stderr
+                .lines()
+                .find_map(|line| line.strip_prefix("Error: "))
+                .or_else(|| stderr.lines().last())
+                .unwrap_or("no report")
+                .trim()
+                .to_string();
+            row.status = format!("error: {message}");
+        }
+    }
+    row
+}
+
+// (stdout, stderr) of the finished child, None if it had to be killed
+fn wait_timeout(
+    mut child: std::process::Child,
+    timeout: Duration,
+) -> std::io::Result<Option<(String, String)>> {
+    let start = Instant::now();
+    while child.try_wait()?.is_none() {
+        if start.elapsed() > timeout {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    // the reports are a single line, far below what the pipes buffer
+    let (mut stdout, mut stderr) = (String::new(), String::new());
+    child.stdout.take().expect("stdout is piped").read_to_string(&mut stdout)?;
+    child.stderr.take().expect("stderr is piped").read_to_string(&mut stderr)?;
+    Ok(Some((stdout, stderr)))
+}
+
+fn is_csv(path: &Path) -> bool {
+    path.extension().map_or(false, |ext| ext == "csv")
+}
+
+const CSV_HEADER: &str = "day,part,variant,median_ns,peak_rss_kb,status";
+const MARKDOWN_HEADER: &str = "| Day | Part | Variant | Median (ms) | Peak memory (KiB) | Status |";
+
+pub fn write_report(rows: &[Row], csv: bool) -> String {
+    let mut report = String::new();
+    if csv {
+        writeln!(report, "{CSV_HEADER}").unwrap();
+    } else {
+        writeln!(report, "{MARKDOWN_HEADER}\n|---:|:---|:---|---:|---:|:---|").unwrap();
+    }
+    for row in rows {
+        let peak = row.peak_rss_kb.map_or(String::new(), |peak| peak.to_string());
+        if csv {
+            let median = row.median.map_or(String::new(), |median| median.as_nanos().to_string());
+            // commas in error messages would shift the columns
+            let status = row.status.replace(',', ";");
+            let fields = [&row.day.to_string(), &row.part, &row.variant, &median, &peak, &status];
+            writeln!(report, "{}", fields.map(String::as_str).join(",")).unwrap();
+        } else {
+            let median = row
+                .median
+                .map_or(String::new(), |median| format!("{:.3}", median.as_secs_f64() * 1000.0));
+            let status = row.status.replace('|', "\\|");
+            writeln!(
+                report,
+                "| {} | {} | {} | {median} | {peak} | {status} |",
+                row.day, row.part, row.variant
+            )
+            .unwrap();
+        }
+    }
+    report
+}
+
+pub fn read_report(path: &Path) -> Result<Vec<Row>, DynError> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Could not read report {}: {e}", path.display()))?;
+    parse_report(&content, is_csv(path)).map_err(|e| format!("{}: {e}", path.display()).into())
+}
+
+fn parse_report(content: &str, csv: bool) -> Result<Vec<Row>, String> {
+    let mut rows = Vec::new();
+    // both formats start with a header, markdown also has the alignment line
+    for line in content.lines().skip(if csv { 1 } else { 2 }).filter(|line| !line.is_empty()) {
+        let fields: Vec<&str> = if csv {
+            line.split(',').collect()
+        } else {
+            line.trim().trim_matches('|').split(" | ").map(str::trim).collect()
+        };
+        let [day, part, variant, median, peak, status] = fields[..] else {
+            return Err(format!("Expected 6 columns in {line:?}"));
+        };
+        let median = match median {
+            "" => None,
+            median if csv => Some(Duration::from_nanos(median.parse().map_err(|_| line)?)),
+            median => {
+                Some(Duration::from_secs_f64(median.parse::<f64>().map_err(|_| line)? / 1000.0))
+            }
+        };
+        rows.push(Row {
+            day: day.parse().map_err(|_| format!("Bad day in {line:?}"))?,
+            part: part.to_string(),
+            variant: variant.to_string(),
+            median,
+            peak_rss_kb: peak.parse().ok(),
+            status: status.replace("\\|", "|"),
+        });
+    }
+    Ok(rows)
+}
+
+/// Solutions whose median grew by more than `threshold` percent since `baseline`
+pub fn regressions(baseline: &[Row], rows: &[Row], threshold: f64) -> Vec<String> {
+    let mut regressions = Vec::new();
+    for row in rows {
+        let Some(before) = baseline.iter().find(|before| before.key() == row.key()) else {
+            continue;
+        };
+        let label = format!("day{}{} [{}]", row.day, row.part, row.variant);
+        match (before.median, row.median) {
+            (Some(before), Some(after)) => {
+                let change = (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+                if change > threshold {
+                    regressions
+                        .push(format!("{label} {before:.3?} -> {after:.3?} (+{change:.0}%)"));
+                }
+            }
+            (Some(_), None) => regressions.push(format!("{label} now fails: {}", row.status)),
+            _ => {}
+        }
+    }
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(day: u32, variant: &str, median_us: Option<u64>, status: &str) -> Row {
+        Row {
+            day,
+            part: "b".to_string(),
+            variant: variant.to_string(),
+            median: median_us.map(Duration::from_micros),
+            peak_rss_kb: median_us.map(|_| 2048),
+            status: status.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_report_round_trip() {
+        let rows = vec![
+            row(5, "maps", Some(1500), "ok"),
+            row(8, "bruteforce", None, "timeout"),
+            row(8, "smartbf", None, "error: a, b | c"),
+        ];
+        let markdown = write_report(&rows, false);
+        assert!(markdown.contains("| 5 | b | maps | 1.500 | 2048 | ok |\n"));
+        assert_eq!(parse_report(&markdown, false).unwrap(), rows);
+
+        let csv = write_report(&rows, true);
+        assert!(csv.starts_with(
+            "day,part,variant,median_ns,peak_rss_kb,status\n5,b,maps,1500000,2048,ok\n"
+        ));
+        let parsed = parse_report(&csv, true).unwrap();
+        assert_eq!(parsed[..2], rows[..2]);
+        assert_eq!(parsed[2].status, "error: a; b | c");
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = vec![
+            row(5, "maps", Some(1000), "ok"),
+            row(6, "default", Some(1000), "ok"),
+            row(7, "default", Some(1000), "ok"),
+        ];
+        let rows = vec![
+            row(5, "maps", Some(1050), "ok"),
+            row(6, "default", Some(1200), "ok"),
+            row(7, "default", None, "timeout"),
+            row(9, "default", Some(1), "ok"),
+        ];
+        assert_eq!(
+            regressions(&baseline, &rows, 10.0),
+            vec![
+                "day6b [default] 1.000ms -> 1.200ms (+20%)".to_string(),
+                "day7b [default] now fails: timeout".to_string(),
+            ]
+        );
+        assert_eq!(regressions(&baseline, &rows, 25.0).len(), 1);
+    }
+}
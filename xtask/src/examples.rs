@@ -0,0 +1,212 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::Parser;
+
+use aoc_config::Config;
+
+use crate::answers::record_candidate;
+use crate::{CommandImpl, DynError};
+
+/// Extract the example inputs and answers from a saved puzzle description
+#[derive(Parser, Debug)]
+pub struct Examples {
+    /// Day of the puzzle, 1 to 25
+    #[clap(long, short, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    /// [default: year in aoc.toml]
+    #[clap(long)]
+    year: Option<u32>,
+    /// Saved puzzle page [default: puzzle.html in the day's inputs directory]
+    #[clap(long)]
+    html: Option<PathBuf>,
+}
+
+impl CommandImpl for Examples {
+    fn main(&self, config: &Config) -> Result<(), DynError> {
+        let year = self.year.unwrap_or(config.year);
+        let inputs = config.inputs_dir(year, self.day);
+        let html_path = self.html.clone().unwrap_or_else(|| inputs.join("puzzle.html"));
+        let html = fs::read_to_string(&html_path)
+            .map_err(|e| format!("Could not read {}: {e}", html_path.display()))?;
+        let puzzle = parse_puzzle(&html);
+        if puzzle.examples.is_empty() {
+            return Err(format!("No <pre><code> blocks in {}", html_path.display()).into());
+        }
+
+        fs::create_dir_all(&inputs)?;
+        let mut names = Vec::new();
+        for example in &puzzle.examples {
+            let (name, created) = save_example(&inputs, example)?;
+            if created {
+                println!("Created {}", inputs.join(format!("{name}.txt")).display());
+            } else {
+                println!("{name}.txt already holds this example");
+            }
+            names.push(name);
+        }
+
+        let answers = config.answers_path(year);
+        let candidates = config.candidates_path(year);
+        for answer in &puzzle.answers {
+            let input = &names[answer.example];
+            let (part, value) = (answer.part, &answer.value);
+            if record_candidate(&answers, &candidates, self.day, part, input, value)? {
+                println!("Recorded {value} as a candidate for part {part} on {input}");
+            } else {
+                println!("Part {part} already has an answer or candidate for {input}, leaving it");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Puzzle {
+    pub examples: Vec<String>,
+    pub answers: Vec<ExampleAnswer>,
+}
+
+/// The emphasized answer a part gives for one of the examples
+#[derive(Debug, PartialEq)]
+pub struct ExampleAnswer {
+    pub part: &'static str,
+    /// Index into `Puzzle::examples`
+    pub example: usize,
+    pub value: String,
+}
+
+// Each part's description is an <article>. Its answer for the example is the last emphasized
+// code in it, and belongs to the last example shown before it (part b often reuses part a's).
+pub fn parse_puzzle(html: &str) -> Puzzle {
+    let blocks = find_all(html, "<pre><code>", "</code></pre>");
+    let examples = blocks.iter().map(|&(_, block)| clean(block).trim_end().to_string()).collect();
+
+    let mut answers = Vec::new();
+    for (&part, (article_start, article)) in
+        ["a", "b"].iter().zip(find_all(html, "<article", "</article>"))
+    {
+        let emphasized = find_all(article, "<code><em>", "</em></code>")
+            .into_iter()
+            .chain(find_all(article, "<em><code>", "</code></em>"))
+            .max_by_key(|&(start, _)| start);
+        let Some((start, value)) = emphasized else {
+            continue;
+        };
+        let position = article_start + start;
+        if let Some(example) = blocks.iter().rposition(|&(block_start, _)| block_start < position) {
+            answers.push(ExampleAnswer { part, example, value: clean(value) });
+        }
+    }
+    Puzzle { examples, answers }
+}
+
+// Reuses the test file that already holds `example`, otherwise writes the first free testK.txt.
+// Empty files count as free, `new-day` leaves an empty test1.txt to paste the first example into.
+fn save_example(inputs: &Path, example: &str) -> Result<(String, bool), DynError> {
+    for k in 1.. {
+        let name = format!("test{k}");
+        let path = inputs.join(format!("{name}.txt"));
+        match fs::read_to_string(&path) {
+            Ok(existing) if existing.trim_end() == example => return Ok((name, false)),
+            Ok(existing) if !existing.trim().is_empty() => continue,
+            _ => {
+                fs::write(&path, example)?;
+                return Ok((name, true));
+            }
+        }
+    }
+    unreachable!("There is always a free test file name")
+}
+
+// (offset of the content, content) for every `start ... end` in `text`
+fn find_all<'a>(text: &'a str, start: &str, end: &str) -> Vec<(usize, &'a str)> {
+    let mut found = Vec::new();
+    let mut offset = 0;
+    while let Some(i) = text[offset..].find(start) {
+        let content_start = offset + i + start.len();
+        let Some(len) = text[content_start..].find(end) else {
+            break;
+        };
+        found.push((content_start, &text[content_start..content_start + len]));
+        offset = content_start + len + end.len();
+    }
+    found
+}
+
+// strips markup such as <em> inside examples and decodes the entities AoC uses
+fn clean(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const PAGE: &str = "<main>\n\
+        <article class=\"day-desc\"><h2>--- Day 1: Trebuchet?! ---</h2>\
+        <p>For example:</p>\n<pre><code>1abc2\npqr3stu8vwx\n</code></pre>\n\
+        <p>Adding these together produces <code><em>142</em></code>.</p>\n</article>\n\
+        <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\
+        <pre><code>two1nine\n<em>x</em> -&gt; 7&amp;\n</code></pre>\n\
+        <p>Adding these together produces <em><code>281</code></em>.</p>\n</article>\n\
+        <p>Your puzzle answer was <code>54304</code>.</p></main>";
+
+    #[test]
+    fn test_parse_puzzle() {
+        let puzzle = parse_puzzle(PAGE);
+        assert_eq!(puzzle.examples, vec!["1abc2\npqr3stu8vwx", "two1nine\nx -> 7&"]);
+        assert_eq!(
+            puzzle.answers,
+            vec![
+                ExampleAnswer { part: "a", example: 0, value: "142".to_string() },
+                ExampleAnswer { part: "b", example: 1, value: "281".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_save_example() {
+        let inputs = env::temp_dir().join(format!("xtask-examples-{}", std::process::id()));
+        fs::create_dir_all(&inputs).unwrap();
+        fs::write(inputs.join("test1.txt"), "other").unwrap();
+        fs::write(inputs.join("test2.txt"), "1abc2\n").unwrap();
+
+        assert_eq!(save_example(&inputs, "1abc2").unwrap(), ("test2".to_string(), false));
+        assert_eq!(save_example(&inputs, "two1nine").unwrap(), ("test3".to_string(), true));
+        assert_eq!(fs::read_to_string(inputs.join("test3.txt")).unwrap(), "two1nine");
+        fs::remove_dir_all(inputs).unwrap();
+    }
+
+    #[test]
+    fn test_save_example_after_new_day() {
+        // what `new-day` leaves behind: empty placeholders for the example and the challenge
+        let inputs = env::temp_dir().join(format!("xtask-examples-new-{}", std::process::id()));
+        fs::create_dir_all(&inputs).unwrap();
+        fs::write(inputs.join("test1.txt"), "").unwrap();
+        fs::write(inputs.join("challenge1.txt"), "").unwrap();
+        fs::write(inputs.join("test2.txt"), " \n").unwrap();
+
+        assert_eq!(save_example(&inputs, "1abc2").unwrap(), ("test1".to_string(), true));
+        assert_eq!(save_example(&inputs, "two1nine").unwrap(), ("test2".to_string(), true));
+        assert_eq!(save_example(&inputs, "1abc2").unwrap(), ("test1".to_string(), false));
+        assert_eq!(fs::read_to_string(inputs.join("test1.txt")).unwrap(), "1abc2");
+        assert_eq!(fs::read_to_string(inputs.join("test2.txt")).unwrap(), "two1nine");
+        fs::remove_dir_all(inputs).unwrap();
+    }
+}
@@ -1,16 +1,28 @@
+use aoc_config::Config;
 use enum_dispatch::enum_dispatch;
 use std::env;
+use std::error::Error;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
 use std::path::Path;
-use std::process::Command;
-use std::{error::Error, path::PathBuf};
 
 type DynError = Box<dyn Error>;
 
+use bench::Bench;
 use clap::Parser;
+use examples::Examples;
+use server::Server;
+use submit::Submit;
+
+mod answers;
+mod bench;
+mod examples;
+mod server;
+mod submit;
 
 #[enum_dispatch]
 trait CommandImpl {
-    fn main(&self) -> Result<(), DynError>;
+    fn main(&self, config: &Config) -> Result<(), DynError>;
 }
 
 #[derive(Parser, Debug)]
@@ -23,43 +35,217 @@ struct Opts {
 #[derive(Parser, Debug)]
 enum SubCommand {
     NewDay(NewDay),
+    Fetch(Fetch),
+    Submit(Submit),
+    Examples(Examples),
+    Bench(Bench),
 }
 fn main() -> Result<(), DynError> {
     let opts = Opts::parse();
+    // aoc.toml is looked up from the current directory, then from this workspace
+    let config = Config::discover(Path::new(env!("CARGO_MANIFEST_DIR")))?;
 
-    opts.subcommand.main()
+    opts.subcommand.main(&config)
 }
 
 // -------------- Tasks -----------
 
+/// Create both parts of a day from the template, plus its input files
 #[derive(Parser, Debug)]
 struct NewDay {
-    /// Create a new day
-    #[clap(long, short)]
-    name: String,
+    /// Day of the puzzle, 1 to 25
+    #[clap(long, short, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    /// [default: year in aoc.toml]
+    #[clap(long)]
+    year: Option<u32>,
 }
 
 impl CommandImpl for NewDay {
-    fn main(&self) -> Result<(), DynError> {
-        let template = project_root().join("aoc").join("src").join("commands").join("day0.rs");
-        let dest = project_root()
-            .join("aoc")
-            .join("src")
-            .join("commands")
-            .join(format!("{}.rs", self.name));
-
-        let status = Command::new("cp")
-            .current_dir(project_root())
-            .args(&[template.to_str().unwrap(), dest.to_str().unwrap()])
-            .status()?;
-        if !status.success() {
-            return Err("cargo install failed".into());
+    fn main(&self, config: &Config) -> Result<(), DynError> {
+        let year = self.year.unwrap_or(config.year);
+        let template = fs::read_to_string(config.commands.join("day0.rs"))?;
+        // the first day of a new year also creates its solutions directory
+        let commands = config.commands_dir(year);
+        let inputs = config.inputs_dir(year, self.day);
+
+        let mut files = Vec::new();
+        let mut modules = Vec::new();
+        for part in ['a', 'b'] {
+            let path = commands.join(format!("day{}{part}.rs", self.day));
+            let relative = path.strip_prefix(&config.commands)?.display().to_string();
+            modules.push((relative, format!("y{year}_day{}{part}", self.day)));
+            files.push((path, render_day(&template, self.day, part)));
+        }
+        let mod_rs = config.commands.join("mod.rs");
+        let rustfmt_list = add_rustfmt_modules(&fs::read_to_string(&mod_rs)?, &modules)?;
+        for input in ["test1.txt", "challenge1.txt"] {
+            files.push((inputs.join(input), String::new()));
+        }
+
+        // check everything up front so a half-created day is never left behind
+        let existing: Vec<String> = files
+            .iter()
+            .filter(|(path, _)| path.exists())
+            .map(|(path, _)| path.display().to_string())
+            .collect();
+        if !existing.is_empty() {
+            return Err(format!("Refusing to overwrite {}", existing.join(", ")).into());
+        }
+
+        fs::create_dir_all(&commands)?;
+        fs::create_dir_all(&inputs)?;
+        for (path, content) in &files {
+            // create_new still guards against files appearing since the check above
+            OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(path)?
+                .write_all(content.as_bytes())?;
+            println!("Created {}", path.display());
         }
+        // the aoc build script registers every dayN<part>.rs, rustfmt only finds them in this list
+        fs::write(&mod_rs, rustfmt_list)?;
+        println!("Added them to the rustfmt list in {}", mod_rs.display());
+        println!(
+            "Run it with `cargo run --bin aoc -- --year {year} day{}a --input test1`",
+            self.day
+        );
+        Ok(())
+    }
+}
+
+// the template's struct is `Day0`, each part gets its own name such as `Day12a`
+fn render_day(template: &str, day: u32, part: char) -> String {
+    template.replace("Day0", &format!("Day{day}{part}"))
+}
+
+// `#[path]` entries for the `rustfmt_modules` list in commands/mod.rs, kept in the order rustfmt
+// sorts them into
+fn add_rustfmt_modules(mod_rs: &str, modules: &[(String, String)]) -> Result<String, DynError> {
+    let header = "mod rustfmt_modules {\n";
+    let start =
+        mod_rs.find(header).ok_or("commands/mod.rs has no rustfmt_modules list")? + header.len();
+    let end =
+        start + mod_rs[start..].find("\n}").ok_or("The rustfmt_modules list is not closed")?;
+
+    let mut entries: Vec<(String, String)> = mod_rs[start..end]
+        .lines()
+        .collect::<Vec<_>>()
+        .chunks(2)
+        .filter_map(|entry| {
+            let path = entry[0].trim().strip_prefix("#[path = \"")?.strip_suffix("\"]")?;
+            let name = entry.get(1)?.trim().strip_prefix("mod ")?.strip_suffix(';')?;
+            Some((path.to_owned(), name.to_owned()))
+        })
+        .collect();
+    entries.extend(modules.iter().cloned());
+    entries.sort_by(|a, b| a.1.cmp(&b.1));
+    entries.dedup();
+
+    let list: Vec<String> = entries
+        .iter()
+        .map(|(path, name)| format!("    #[path = \"{path}\"]\n    mod {name};"))
+        .collect();
+    Ok(format!("{}{}{}", &mod_rs[..start], list.join("\n"), &mod_rs[end..]))
+}
 
+/// Download a day's puzzle input into challenge1.txt in its inputs directory
+#[derive(Parser, Debug)]
+struct Fetch {
+    /// Day of the puzzle, 1 to 25
+    #[clap(long, short, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    /// [default: year in aoc.toml]
+    #[clap(long)]
+    year: Option<u32>,
+    /// Puzzle server to fetch from [default: $AOC_BASE_URL or the server in aoc.toml]
+    #[clap(long)]
+    base_url: Option<String>,
+}
+
+impl CommandImpl for Fetch {
+    fn main(&self, config: &Config) -> Result<(), DynError> {
+        let year = self.year.unwrap_or(config.year);
+        let dest = config.inputs_dir(year, self.day).join("challenge1.txt");
+        // checked before loading the session so cached inputs need no token
+        if is_cached(&dest) {
+            println!("{} already exists, not fetching it again", dest.display());
+            return Ok(());
+        }
+        let server = Server::from_env(config, self.base_url.as_deref())?;
+        fetch_input(&server, year, self.day, &dest)?;
+        println!("Saved input to {}", dest.display());
         Ok(())
     }
 }
 
-fn project_root() -> PathBuf {
-    Path::new(&env!("CARGO_MANIFEST_DIR")).ancestors().nth(1).unwrap().to_path_buf()
+// new-day leaves an empty placeholder, which still needs fetching
+fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).map_or(false, |metadata| metadata.len() > 0)
+}
+
+fn fetch_input(server: &Server, year: u32, day: u32, dest: &Path) -> Result<(), DynError> {
+    let input = server.get(&format!("{}/input", server.day_url(year, day)))?;
+    if input.is_empty() {
+        return Err(format!("Server returned an empty input for day {day}").into());
+    }
+    fs::create_dir_all(dest.parent().expect("Inputs live in a directory"))?;
+    // write to a temporary file first so an interrupted fetch never looks cached
+    let partial = dest.with_extension("part");
+    fs::write(&partial, input)?;
+    fs::rename(&partial, dest)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_day() {
+        let config = Config::discover(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+        let template = fs::read_to_string(config.commands.join("day0.rs")).unwrap();
+        let rendered = render_day(&template, 12, 'b');
+        assert!(rendered.contains("pub struct Day12b;"));
+        assert!(rendered.contains("impl CommandImpl for Day12b {"));
+        assert!(!rendered.contains("Day0"));
+    }
+
+    #[test]
+    fn test_add_rustfmt_modules() {
+        let mod_rs = "use x;\n\n#[cfg(rustfmt)]\n#[path = \".\"]\nmod rustfmt_modules {\n    \
+                      #[path = \"y2023/day9a.rs\"]\n    mod y2023_day9a;\n}\n\nfn f() {}\n";
+        let modules = [
+            ("y2023/day10a.rs".to_owned(), "y2023_day10a".to_owned()),
+            ("y2023/day10b.rs".to_owned(), "y2023_day10b".to_owned()),
+        ];
+        let updated = add_rustfmt_modules(mod_rs, &modules).unwrap();
+        assert_eq!(
+            updated,
+            "use x;\n\n#[cfg(rustfmt)]\n#[path = \".\"]\nmod rustfmt_modules {\n    \
+             #[path = \"y2023/day10a.rs\"]\n    mod y2023_day10a;\n    \
+             #[path = \"y2023/day10b.rs\"]\n    mod y2023_day10b;\n    \
+             #[path = \"y2023/day9a.rs\"]\n    mod y2023_day9a;\n}\n\nfn f() {}\n"
+        );
+        // the real list is one rustfmt accepts as it is
+        let config = Config::discover(Path::new(env!("CARGO_MANIFEST_DIR"))).unwrap();
+        let mod_rs = fs::read_to_string(config.commands.join("mod.rs")).unwrap();
+        assert_eq!(add_rustfmt_modules(&mod_rs, &[]).unwrap(), mod_rs);
+        assert!(add_rustfmt_modules("fn f() {}", &modules).is_err());
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (url, requests) = server::mock::serve(vec!["0 3 6\n"]);
+        let dest =
+            env::temp_dir().join(format!("xtask-fetch-{}", std::process::id())).join("in.txt");
+        assert!(!is_cached(&dest));
+
+        fetch_input(&Server::new(&url, "secret"), 2023, 9, &dest).unwrap();
+        assert!(requests.recv().unwrap().starts_with("GET /2023/day/9/input "));
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "0 3 6\n");
+        assert!(is_cached(&dest));
+        fs::remove_dir_all(dest.parent().unwrap()).unwrap();
+    }
 }
@@ -0,0 +1,171 @@
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use aoc_config::Config;
+
+use crate::DynError;
+
+const USER_AGENT: &str = "github.com/patbohn/aoc-2023 xtask";
+
+/// Connection settings for the puzzle server. Requests go through `curl`.
+#[derive(Debug, Clone)]
+pub struct Server {
+    pub base_url: String,
+    session: String,
+}
+
+impl Server {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Server {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// Base URL from `--base-url`, `AOC_BASE_URL` or `aoc.toml`, session token from `AOC_SESSION`
+    /// or the file named by `AOC_SESSION_FILE` (default: `.session` next to `aoc.toml`)
+    pub fn from_env(config: &Config, base_url: Option<&str>) -> Result<Self, DynError> {
+        let base_url = match base_url {
+            Some(base_url) => base_url.to_string(),
+            None => env::var("AOC_BASE_URL").unwrap_or_else(|_| config.server.clone()),
+        };
+        Ok(Server::new(&base_url, &session_token(config)?))
+    }
+
+    pub fn day_url(&self, year: u32, day: u32) -> String {
+        format!("{}/{year}/day/{day}", self.base_url)
+    }
+
+    pub fn get(&self, url: &str) -> Result<String, DynError> {
+        self.curl(url, &[])
+    }
+
+    /// POST `form` as `application/x-www-form-urlencoded`
+    pub fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String, DynError> {
+        let fields: Vec<String> =
+            form.iter().map(|(key, value)| format!("{key}={value}")).collect();
+        let mut args = Vec::new();
+        for field in &fields {
+            args.extend(["--data-urlencode", field.as_str()]);
+        }
+        self.curl(url, &args)
+    }
+
+    fn curl(&self, url: &str, args: &[&str]) -> Result<String, DynError> {
+        // the session goes in through a config on stdin, on the command line any user could read
+        // it from the process list
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--user-agent", USER_AGENT])
+            .args(["--config", "-"])
+            .args(args)
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Could not run curl: {e}"))?;
+        let session = self.session.replace('\\', "\\\\").replace('"', "\\\"");
+        let mut stdin = curl.stdin.take().expect("stdin is piped");
+        writeln!(stdin, "cookie = \"session={session}\"")?;
+        drop(stdin);
+        let output = curl.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Request to {url} failed: {}", stderr.trim()).into());
+        }
+        Ok(String::from_utf8(output.stdout)?)
+    }
+}
+
+fn session_token(config: &Config) -> Result<String, DynError> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+    let path = env::var("AOC_SESSION_FILE")
+        .map(Into::into)
+        .unwrap_or_else(|_| config.root.join(".session"));
+    read_session(&path)
+}
+
+fn read_session(path: &Path) -> Result<String, DynError> {
+    let session = fs::read_to_string(path).map_err(|e| {
+        format!("No session token: set AOC_SESSION or put it in {} ({e})", path.display())
+    })?;
+    Ok(session.trim().to_string())
+}
+
+#[cfg(test)]
+pub mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// Serve `responses` to consecutive requests on a local port, handing back each raw request
+    pub fn serve(responses: Vec<&'static str>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for body in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_ascii_lowercase().strip_prefix("content-length:")
+                    {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                sender.send(request).unwrap();
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_sends_session() {
+        let (url, requests) = mock::serve(vec!["1 2 3\n"]);
+        let server = Server::new(&format!("{url}/"), "secret");
+        assert_eq!(server.day_url(2023, 5), format!("{url}/2023/day/5"));
+        assert_eq!(server.get(&format!("{url}/2023/day/5/input")).unwrap(), "1 2 3\n");
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2023/day/5/input "));
+        assert!(request.contains("Cookie: session=secret"));
+    }
+
+    #[test]
+    fn test_post_encodes_form() {
+        let (url, requests) = mock::serve(vec!["ok"]);
+        let server = Server::new(&url, "secret");
+        let answer_url = format!("{}/answer", server.day_url(2023, 5));
+        assert_eq!(server.post(&answer_url, &[("level", "1"), ("answer", "a b")]).unwrap(), "ok");
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer "));
+        assert!(request.ends_with("\r\n\r\nlevel=1&answer=a+b"));
+    }
+}
@@ -0,0 +1,344 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_config::Config;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+
+use crate::answers::record_answer;
+use crate::server::Server;
+use crate::{CommandImpl, DynError};
+
+// the puzzle server rejects answers sent in quick succession
+const MIN_INTERVAL_SECS: u64 = 60;
+
+/// Submit an answer to the puzzle server, keeping a history of every attempt
+#[derive(Parser, Debug)]
+pub struct Submit {
+    /// Day of the puzzle, 1 to 25
+    #[clap(long, short, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+    #[clap(long, short, value_parser = ["a", "b"])]
+    part: String,
+    /// Answer to submit [default: run the solution on challenge1]
+    #[clap(long, short)]
+    answer: Option<String>,
+    /// [default: year in aoc.toml]
+    #[clap(long)]
+    year: Option<u32>,
+    /// Puzzle server to submit to [default: $AOC_BASE_URL or the server in aoc.toml]
+    #[clap(long)]
+    base_url: Option<String>,
+}
+
+impl CommandImpl for Submit {
+    fn main(&self, config: &Config) -> Result<(), DynError> {
+        let year = self.year.unwrap_or(config.year);
+        let answer = match &self.answer {
+            Some(answer) => answer.trim().to_string(),
+            None => run_solution(&config.root, year, self.day, &self.part)?,
+        };
+        let attempt = Attempt {
+            timestamp: now(),
+            year,
+            day: self.day,
+            part: self.part.clone(),
+            answer,
+            verdict: Verdict::Unknown,
+            wait_secs: None,
+        };
+        let history_path = config.outputs.join("submissions.jsonl");
+        check(&load_history(&history_path)?, &attempt)?;
+
+        let server = Server::from_env(config, self.base_url.as_deref())?;
+        println!("Submitting {} for day {} part {}", attempt.answer, self.day, self.part);
+        let attempt = submit(&server, attempt)?;
+        append_history(&history_path, &attempt)?;
+
+        if attempt.verdict == Verdict::Correct {
+            let answers = config.answers_path(year);
+            if let Some(previous) =
+                record_answer(&answers, self.day, &self.part, "challenge1", &attempt.answer)?
+            {
+                println!("Replaced {previous}, which was recorded as the answer before");
+            }
+            println!("Recorded the answer in {}", answers.display());
+        }
+        Ok(())
+    }
+}
+
+/// One submission and how the server judged it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub timestamp: u64,
+    pub year: u32,
+    pub day: u32,
+    pub part: String,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// How long the server asked to wait before the next submission
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    Wait,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    fn is_rejection(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+pub fn load_history(path: &Path) -> Result<Vec<Attempt>, DynError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        // nothing submitted yet
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Could not read {}: {e}", path.display()).into()),
+    };
+    let mut history = Vec::new();
+    for (i, line) in content.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        let attempt =
+            serde_json::from_str(line).map_err(|e| format!("{}:{}: {e}", path.display(), i + 1))?;
+        history.push(attempt);
+    }
+    Ok(history)
+}
+
+fn append_history(path: &Path, attempt: &Attempt) -> Result<(), DynError> {
+    fs::create_dir_all(path.parent().expect("History lives in a directory"))?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(attempt)?)?;
+    Ok(())
+}
+
+/// Refuse submissions the history already rules out, or that would hit the server's rate limit
+pub fn check(history: &[Attempt], attempt: &Attempt) -> Result<(), String> {
+    if let Some(last) = history.last() {
+        let wait = last.wait_secs.unwrap_or(0).max(MIN_INTERVAL_SECS);
+        let ready = last.timestamp + wait;
+        if attempt.timestamp < ready {
+            return Err(format!(
+                "Last submission was too recent, wait another {}s",
+                ready - attempt.timestamp
+            ));
+        }
+    }
+
+    let same_part = history.iter().filter(|previous| {
+        (previous.year, previous.day, &previous.part) == (attempt.year, attempt.day, &attempt.part)
+    });
+    let value: Option<i128> = attempt.answer.parse().ok();
+    for previous in same_part {
+        match previous.verdict {
+            Verdict::Correct => {
+                return Err(format!("Already solved with {}", previous.answer));
+            }
+            verdict if verdict.is_rejection() && previous.answer == attempt.answer => {
+                return Err(format!("{} was already rejected ({verdict:?})", attempt.answer));
+            }
+            _ => {}
+        }
+        if let (Some(value), Ok(bound)) = (value, previous.answer.parse::<i128>()) {
+            if previous.verdict == Verdict::TooHigh && value >= bound {
+                return Err(format!("{value} can't be right, {bound} was already too high"));
+            }
+            if previous.verdict == Verdict::TooLow && value <= bound {
+                return Err(format!("{value} can't be right, {bound} was already too low"));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn submit(server: &Server, mut attempt: Attempt) -> Result<Attempt, DynError> {
+    let url = format!("{}/answer", server.day_url(attempt.year, attempt.day));
+    let level = if attempt.part == "a" { "1" } else { "2" };
+    let response = server.post(&url, &[("level", level), ("answer", &attempt.answer)])?;
+    let message = article_text(&response);
+    println!("{message}");
+    attempt.verdict = parse_verdict(&message);
+    if attempt.verdict == Verdict::Wait {
+        attempt.wait_secs = parse_wait(&message);
+    }
+    Ok(attempt)
+}
+
+pub fn parse_verdict(message: &str) -> Verdict {
+    let message = message.to_lowercase();
+    if message.contains("that's the right answer") {
+        Verdict::Correct
+    } else if message.contains("you don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else if message.contains("you gave an answer too recently") {
+        Verdict::Wait
+    } else if message.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if message.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if message.contains("that's not the right answer") {
+        Verdict::Wrong
+    } else {
+        Verdict::Unknown
+    }
+}
+
+// "You have 1m 5s left to wait."
+fn parse_wait(message: &str) -> Option<u64> {
+    let (before, _) = message.split_once(" left to wait")?;
+    let duration = before.rsplit_once("You have ")?.1;
+    let mut secs = 0;
+    for token in duration.split_whitespace() {
+        let (number, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let number: u64 = number.parse().ok()?;
+        secs += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(secs)
+}
+
+// the verdict is in the page's <article>, stripped of its markup
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article.split_once('>').map_or(article, |(_, body)| body));
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// runs the default variant on the challenge input through the runner's JSON output
+fn run_solution(root: &Path, year: u32, day: u32, part: &str) -> Result<String, DynError> {
+    let output = Command::new(env!("CARGO"))
+        .current_dir(root)
+        .args(["run", "--release", "--quiet", "--bin", "aoc", "--"])
+        .args(["--year", &year.to_string(), &format!("day{day}{part}")])
+        .args(["--input", "challenge1", "--format", "json"])
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()?;
+    let stdout = String::from_utf8(output.stdout)?;
+    let record: serde_json::Value = serde_json::from_str(stdout.lines().last().unwrap_or(""))
+        .map_err(|e| format!("Could not read the answer from the runner: {e}"))?;
+    match (record["answer"].as_str(), record["error"].as_str()) {
+        (Some(answer), _) => Ok(answer.to_string()),
+        (None, Some(e)) => Err(format!("Day {day} part {part} failed: {e}").into()),
+        (None, None) => Err("Runner did not return an answer".into()),
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).expect("Clock is after 1970").as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::mock;
+
+    fn attempt(timestamp: u64, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt {
+            timestamp,
+            year: 2023,
+            day: 5,
+            part: "b".to_string(),
+            answer: answer.to_string(),
+            verdict,
+            wait_secs: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = "<html><main><article><p>That's not the right answer; your answer is too \
+            high.  If you're stuck, ... <a href=\"/2023/day/5\">[Return to Day 5]</a></p>\
+            </article></main></html>";
+        assert_eq!(
+            article_text(page),
+            "That's not the right answer; your answer is too high. If you're stuck, ... [Return to Day 5]"
+        );
+        assert_eq!(parse_verdict(&article_text(page)), Verdict::TooHigh);
+        assert_eq!(
+            parse_verdict("That's the right answer! You are one gold star closer"),
+            Verdict::Correct
+        );
+        assert_eq!(parse_verdict("That's not the right answer."), Verdict::Wrong);
+        let wait =
+            "You gave an answer too recently; you have to wait. You have 1m 5s left to wait.";
+        assert_eq!(parse_verdict(wait), Verdict::Wait);
+        assert_eq!(parse_wait(wait), Some(65));
+    }
+
+    #[test]
+    fn test_check() {
+        let history = vec![
+            attempt(1000, "500", Verdict::TooHigh),
+            attempt(1100, "100", Verdict::TooLow),
+            attempt(1200, "abc", Verdict::Wrong),
+        ];
+        assert!(check(&history, &attempt(1210, "300", Verdict::Unknown)).is_err());
+        assert!(check(&history, &attempt(1300, "300", Verdict::Unknown)).is_ok());
+        assert!(check(&history, &attempt(1300, "500", Verdict::Unknown)).is_err());
+        assert!(check(&history, &attempt(1300, "600", Verdict::Unknown)).is_err());
+        assert!(check(&history, &attempt(1300, "99", Verdict::Unknown)).is_err());
+        assert!(check(&history, &attempt(1300, "abc", Verdict::Unknown)).is_err());
+
+        let mut waiting = attempt(1300, "300", Verdict::Wait);
+        waiting.wait_secs = Some(300);
+        let history = vec![waiting, attempt(1700, "200", Verdict::Correct)];
+        assert!(check(&history[..1], &attempt(1400, "300", Verdict::Unknown)).is_err());
+        assert_eq!(
+            check(&history, &attempt(2000, "250", Verdict::Unknown)),
+            Err("Already solved with 200".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, requests) =
+            mock::serve(vec!["<article><p>That's the right answer!</p></article>"]);
+        let attempt =
+            submit(&Server::new(&url, "secret"), attempt(0, "42", Verdict::Unknown)).unwrap();
+        assert_eq!(attempt.verdict, Verdict::Correct);
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2023/day/5/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn test_load_history() {
+        let dir = std::env::temp_dir().join(format!("xtask-history-{}", std::process::id()));
+        assert!(load_history(&dir.join("missing.jsonl")).unwrap().is_empty());
+        // anything but a missing file must not look like an empty history
+        fs::create_dir_all(&dir).unwrap();
+        assert!(load_history(&dir).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}